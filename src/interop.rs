@@ -56,6 +56,8 @@ impl From<&tex::Person> for Person {
             prefix: optional(&person.prefix),
             suffix: optional(&person.suffix),
             alias: None,
+            affiliation: None,
+            acronym: None,
         }
     }
 }
@@ -415,26 +417,15 @@ impl TryFrom<&tex::Entry> for Entry {
             }
         }
 
-        if let Some(publisher) = map_res(entry.publisher())?.map(|d| {
-            let mut format = FmtString::new_empty(false, true, false);
-            let comma = FmtString {
-                value: ", ".into(),
-                sentence_case: Some(", ".into()),
-                title_case: None,
-                verbatim: false,
-            };
-            for (i, item) in d.iter().enumerate() {
-                if i != 0 {
-                    format.extend(comma.clone());
-                }
-                format.extend(item.as_slice().into());
-            }
-            format
+        if let Some(publishers) = map_res(entry.publisher())?.map(|d| {
+            d.iter()
+                .map(|item| item.as_slice().into())
+                .collect::<Vec<FmtString>>()
         }) {
             if let Some(parent) = book(&mut item, parent) {
-                parent.set_publisher(publisher);
+                parent.set_publishers(publishers);
             } else {
-                item.set_publisher(publisher);
+                item.set_publishers(publishers);
             }
         }
 
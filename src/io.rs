@@ -1,4 +1,4 @@
-//! Reading and writing YAML bibliographies.
+//! Reading and writing YAML bibliographies and exporting them as RIS.
 
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -283,6 +283,28 @@ fn title_from_hash_map(
     Ok(Title { canonical, shorthand, translated })
 }
 
+fn fmt_str_from_yaml(
+    yaml: Yaml,
+    key: &str,
+    field_name: &str,
+) -> Result<FmtString, YamlBibliographyError> {
+    match yaml {
+        Yaml::Hash(map) => fmt_str_from_hash_map(map).map_err(|e| {
+            YamlBibliographyError::new_data_type_src_error(
+                key,
+                field_name,
+                YamlDataTypeError::FmtString(e),
+            )
+        }),
+        Yaml::String(t) => Ok(FmtString::new(t)),
+        _ => Err(YamlBibliographyError::new_data_type_error(
+            key,
+            field_name,
+            "text or formattable string",
+        )),
+    }
+}
+
 fn fmt_str_from_hash_map(
     map: LinkedHashMap<Yaml, Yaml>,
 ) -> Result<FmtString, YamlFmtStringError> {
@@ -341,7 +363,8 @@ fn person_from_yaml(
             )
         })?;
 
-        let optionals = ["given-name", "prefix", "suffix", "alias"];
+        let optionals =
+            ["given-name", "prefix", "suffix", "alias", "affiliation", "acronym"];
         let mut values = vec![];
 
         for &field in optionals.iter() {
@@ -350,6 +373,8 @@ fn person_from_yaml(
 
         Ok(Person {
             name,
+            acronym: values.pop().unwrap(),
+            affiliation: values.pop().unwrap(),
             alias: values.pop().unwrap(),
             suffix: values.pop().unwrap(),
             prefix: values.pop().unwrap(),
@@ -490,25 +515,22 @@ fn entry_from_yaml(
                     ));
                 }
             },
-            "publisher" | "location" | "archive" | "archive-location" => match yaml {
-                Yaml::Hash(map) => {
-                    Value::FmtString(fmt_str_from_hash_map(map).map_err(|e| {
-                        YamlBibliographyError::new_data_type_src_error(
-                            &key,
-                            &field_name,
-                            YamlDataTypeError::FmtString(e),
-                        )
-                    })?)
-                }
-                Yaml::String(t) => Value::FmtString(FmtString::new(t)),
-                _ => {
-                    return Err(YamlBibliographyError::new_data_type_error(
-                        &key,
-                        &field_name,
-                        "text or formattable string",
-                    ));
+            "publisher" | "location" => {
+                let items = match yaml {
+                    Yaml::Array(items) => items,
+                    other => vec![other],
+                };
+
+                let mut parts = vec![];
+                for item in items {
+                    parts.push(fmt_str_from_yaml(item, &key, &field_name)?);
                 }
-            },
+
+                Value::FmtStrings(parts)
+            }
+            "archive" | "archive-location" | "volume-title" => {
+                Value::FmtString(fmt_str_from_yaml(yaml, &key, &field_name)?)
+            }
             "author" | "editor" => {
                 Value::Persons(persons_from_yaml(yaml, &key, &field_name)?)
             }
@@ -530,7 +552,7 @@ fn entry_from_yaml(
 
                 Value::PersonsWithRoles(res)
             }
-            "date" => Value::Date(match yaml {
+            "date" | "original-date" | "deposited-date" => Value::Date(match yaml {
                 Yaml::Integer(value) => Date::from_year(value as i32),
                 Yaml::String(value) => Date::from_str(&value).map_err(|e| {
                     YamlBibliographyError::new_data_type_src_error(
@@ -563,7 +585,16 @@ fn entry_from_yaml(
                     ));
                 }
             }
-            "volume-total" | "page-total" => {
+            "early-access" | "anonymous" | "all-authors" | "dead-url" => {
+                Value::Bool(yaml.as_bool().ok_or_else(|| {
+                    YamlBibliographyError::new_data_type_error(
+                        &key,
+                        &field_name,
+                        "boolean",
+                    )
+                })?)
+            }
+            "volume-total" | "page-total" | "cited-by" => {
                 Value::Integer(yaml.into_i64().ok_or_else(|| {
                     YamlBibliographyError::new_data_type_error(
                         &key,
@@ -633,7 +664,7 @@ fn entry_from_yaml(
 
                 Value::TimeRange(v)
             }
-            "url" => {
+            "url" | "archive-url" => {
                 let (url, date) = match yaml {
                     Yaml::String(s) => (
                         Url::parse(&s).map_err(|e| {
@@ -723,6 +754,48 @@ fn entry_from_yaml(
                     )
                 })?,
             ),
+            "languages" => {
+                let items = match yaml {
+                    Yaml::Array(items) => items,
+                    other => vec![other],
+                };
+
+                let mut languages = vec![];
+                for item in items {
+                    languages.push(
+                        item.into_string().and_then(|f| f.parse().ok()).ok_or_else(
+                            || {
+                                YamlBibliographyError::new_data_type_error(
+                                    &key,
+                                    &field_name,
+                                    "list of unicode language identifiers",
+                                )
+                            },
+                        )?,
+                    );
+                }
+
+                Value::Languages(languages)
+            }
+            "see-also" => {
+                let items = match yaml {
+                    Yaml::Array(items) => items,
+                    other => vec![other],
+                };
+
+                let mut keys = vec![];
+                for item in items {
+                    keys.push(item.into_string().ok_or_else(|| {
+                        YamlBibliographyError::new_data_type_error(
+                            &key,
+                            &field_name,
+                            "list of keys",
+                        )
+                    })?);
+                }
+
+                Value::Keys(keys)
+            }
             "parent" => {
                 if yaml.is_array() {
                     let mut entries = vec![];
@@ -880,7 +953,10 @@ impl From<&NumOrStr> for Yaml {
 
 impl From<&Person> for Yaml {
     fn from(person: &Person) -> Self {
-        if let Some(alias) = &person.alias {
+        if person.alias.is_some()
+            || person.affiliation.is_some()
+            || person.acronym.is_some()
+        {
             let mut hm = LinkedHashMap::new();
 
             hm.insert(Yaml::String("name".into()), Yaml::String(person.name.clone()));
@@ -896,7 +972,18 @@ impl From<&Person> for Yaml {
             if let Some(suffix) = &person.suffix {
                 hm.insert(Yaml::String("suffix".into()), Yaml::String(suffix.clone()));
             }
-            hm.insert(Yaml::String("alias".into()), Yaml::String(alias.clone()));
+            if let Some(alias) = &person.alias {
+                hm.insert(Yaml::String("alias".into()), Yaml::String(alias.clone()));
+            }
+            if let Some(affiliation) = &person.affiliation {
+                hm.insert(
+                    Yaml::String("affiliation".into()),
+                    Yaml::String(affiliation.clone()),
+                );
+            }
+            if let Some(acronym) = &person.acronym {
+                hm.insert(Yaml::String("acronym".into()), Yaml::String(acronym.clone()));
+            }
 
             Yaml::Hash(hm)
         } else {
@@ -1015,36 +1102,105 @@ fn affiliateds_into_yaml(pers: &[(Vec<Person>, PersonRole)]) -> Yaml {
     }
 }
 
+impl From<&Value> for Yaml {
+    fn from(item: &Value) -> Self {
+        match item {
+            Value::Title(i) => i.into(),
+            Value::FmtString(i) => i.into(),
+            Value::FmtStrings(i) => match i.as_slice() {
+                [single] => single.into(),
+                _ => Yaml::Array(i.iter().map(Into::into).collect()),
+            },
+            Value::Text(i) => Yaml::String(i.clone()),
+            Value::Integer(i) => Yaml::Integer(*i),
+            Value::Bool(i) => Yaml::Boolean(*i),
+            Value::Date(i) => (*i).into(),
+            Value::Persons(i) => persons_into_yaml(i),
+            Value::PersonsWithRoles(i) => affiliateds_into_yaml(i),
+            Value::IntegerOrText(i) => i.into(),
+            Value::Range(i) => range_into_yaml(i),
+            Value::Duration(i) => (*i).into(),
+            Value::TimeRange(i) => time_range_into_yaml(i.clone()),
+            Value::Url(i) => i.into(),
+            Value::Language(i) => language_into_yaml(i),
+            Value::Languages(i) => {
+                Yaml::Array(i.iter().map(language_into_yaml).collect())
+            }
+            Value::Entries(i) => Yaml::Array(i.iter().map(Into::into).collect()),
+            Value::Keys(i) => Yaml::Array(i.iter().cloned().map(Yaml::String).collect()),
+        }
+    }
+}
+
 impl From<&Entry> for Yaml {
     fn from(entry: &Entry) -> Self {
         let mut hm = LinkedHashMap::new();
         hm.insert(Yaml::String("type".into()), entry.kind().into());
 
         for (s, item) in &entry.content {
-            let content = match item {
-                Value::Title(i) => i.into(),
-                Value::FmtString(i) => i.into(),
-                Value::Text(i) => Yaml::String(i.clone()),
-                Value::Integer(i) => Yaml::Integer(*i),
-                Value::Date(i) => (*i).into(),
-                Value::Persons(i) => persons_into_yaml(i),
-                Value::PersonsWithRoles(i) => affiliateds_into_yaml(i),
-                Value::IntegerOrText(i) => i.into(),
-                Value::Range(i) => range_into_yaml(i),
-                Value::Duration(i) => (*i).into(),
-                Value::TimeRange(i) => time_range_into_yaml(i.clone()),
-                Value::Url(i) => i.into(),
-                Value::Language(i) => language_into_yaml(i),
-                Value::Entries(i) => Yaml::Array(i.iter().map(Into::into).collect()),
-            };
-
-            hm.insert(Yaml::String(s.clone()), content);
+            hm.insert(Yaml::String(s.clone()), item.into());
         }
 
         Yaml::Hash(hm)
     }
 }
 
+impl Entry {
+    /// Lists the key, the type, and all populated fields of the entry in two
+    /// aligned columns for debugging. The fields of parents are listed
+    /// below the field that contains them, indented by two spaces.
+    pub fn debug_table(&self) -> String {
+        let mut res = String::new();
+        self.write_debug_table(&mut res, 0);
+        res
+    }
+
+    fn write_debug_table(&self, res: &mut String, indent: usize) {
+        let mut fields: Vec<_> = self.content.iter().collect();
+        fields.sort_by_key(|&(name, _)| name);
+
+        let width =
+            fields.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max(4) + 1;
+        let row = |name: &str, value: &str| {
+            format!("{:indent$}{:<width$} {}\n", "", format!("{}:", name), value)
+        };
+
+        if !self.key().is_empty() {
+            *res += &row("key", self.key());
+        }
+        *res += &row("type", &self.kind().to_string());
+
+        for (name, value) in fields {
+            if let Value::Entries(entries) = value {
+                *res += &format!("{:indent$}{}:\n", "", name);
+                for entry in entries {
+                    entry.write_debug_table(res, indent + 2);
+                }
+            } else {
+                *res += &row(name, &yaml_debug_string(&value.into()));
+            }
+        }
+    }
+}
+
+/// Flattens YAML into a single line for [`Entry::debug_table`].
+fn yaml_debug_string(yaml: &Yaml) -> String {
+    match yaml {
+        Yaml::String(s) | Yaml::Real(s) => s.clone(),
+        Yaml::Integer(i) => i.to_string(),
+        Yaml::Boolean(b) => b.to_string(),
+        Yaml::Array(items) => {
+            items.iter().map(yaml_debug_string).collect::<Vec<_>>().join("; ")
+        }
+        Yaml::Hash(map) => map
+            .iter()
+            .map(|(k, v)| format!("{}: {}", yaml_debug_string(k), yaml_debug_string(v)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => String::new(),
+    }
+}
+
 /// Serialize a bibliography into the YAML format.
 pub fn to_yaml<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Yaml {
     let mut items = LinkedHashMap::new();
@@ -1064,6 +1220,101 @@ pub fn to_yaml_str<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Option<S
     Some(out_str)
 }
 
+/// Serialize a bibliography into the RIS format used by reference managers.
+///
+/// The container title, volume, and issue of an entry are taken from its
+/// first parent if the entry does not have them itself.
+pub fn to_ris<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> String {
+    let mut res = String::new();
+    for entry in entries {
+        let parent = entry.parents().and_then(|parents| parents.first());
+        let mut line = |tag: &str, value: &str| {
+            res += &format!("{}  - {}\n", tag, value);
+        };
+
+        line("TY", ris_type(entry, parent));
+
+        for author in entry.authors().unwrap_or_default() {
+            line("AU", &author.name_first(false, false));
+        }
+
+        if let Some(title) = entry.title() {
+            line("TI", &title.canonical.value);
+        }
+
+        if let Some(parent) = parent {
+            if let Some(title) = parent.title() {
+                let tag =
+                    if parent.kind() == EntryType::Periodical { "JO" } else { "T2" };
+                line(tag, &title.canonical.value);
+            }
+        }
+
+        let range = |r: &std::ops::Range<i64>| {
+            if r.start == r.end {
+                r.start.to_string()
+            } else {
+                format!("{}-{}", r.start, r.end)
+            }
+        };
+
+        if let Some(volume) = entry.volume().or_else(|| parent?.volume()) {
+            line("VL", &range(volume));
+        }
+
+        if let Some(issue) = entry.issue().or_else(|| parent?.issue()) {
+            line("IS", &issue.to_string());
+        }
+
+        if let Some(pages) = entry.page_range() {
+            line("SP", &pages.start.to_string());
+            if pages.end != pages.start {
+                line("EP", &pages.end.to_string());
+            }
+        }
+
+        if let Some(date) = entry.date_any() {
+            line("PY", &date.year.to_string());
+        }
+
+        if let Some(doi) = entry.doi() {
+            line("DO", doi);
+        }
+
+        if let Some(url) = entry.url() {
+            line("UR", url.value.as_str());
+        }
+
+        line("ER", "");
+    }
+
+    res
+}
+
+/// The RIS reference type of an entry.
+fn ris_type(entry: &Entry, parent: Option<&Entry>) -> &'static str {
+    match (entry.kind(), parent.map(Entry::kind)) {
+        (EntryType::Article, Some(EntryType::Periodical)) => "JOUR",
+        (EntryType::Article, Some(EntryType::Newspaper)) => "NEWS",
+        (EntryType::Article, Some(EntryType::Proceedings))
+        | (EntryType::Article, Some(EntryType::Conference)) => "CPAPER",
+        (EntryType::Chapter, _) | (EntryType::Anthos, _) => "CHAP",
+        (EntryType::Book, _) => "BOOK",
+        (EntryType::Anthology, _) => "EDBOOK",
+        (EntryType::Proceedings, _) => "CONF",
+        (EntryType::Report, _) => "RPRT",
+        (EntryType::Thesis, _) => "THES",
+        (EntryType::Patent, _) => "PAT",
+        (EntryType::Web, _) | (EntryType::Blog, _) => "ELEC",
+        (EntryType::Video, _) => "VIDEO",
+        (EntryType::Audio, _) => "SOUND",
+        (EntryType::Legislation, _) => "STAT",
+        (EntryType::Manuscript, _) => "UNPB",
+        (EntryType::Repository, _) => "COMP",
+        _ => "GEN",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1082,4 +1333,111 @@ mod tests {
             assert_eq!(match_e, &entry);
         }
     }
+
+    #[test]
+    fn roundtrip_mixed() {
+        let contents = r#"
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            affiliated:
+                role: Translator
+                names: ["Muir, Willa", "Muir, Edwin"]
+            date: 1998-05
+            original-date: 1925
+            edition: revised
+            publisher: [Schocken Books, Vintage]
+            location: [New York, London]
+            volume: 2-3
+            volume-title: Complete edition
+        chapter:
+            type: Chapter
+            title: The first chapter
+            author: Doe, Jane
+            affiliated:
+                role: Contributor
+                names: Lee, Kim
+            edition: 2
+            parent:
+                - type: Book
+                  title: { value: Collected essays, verbatim: true }
+                  editor: ["Smith, John", "Lee, Kim"]
+                  date: 2001-02-03
+                  url: { value: "https://example.com", date: 2021-01-07 }
+        "#;
+
+        let entries = from_yaml_str(contents).unwrap();
+        let yaml = to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn ris() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics and luminescence of the excitations
+            author: ["Doan, T. D.", "Haug, H."]
+            date: 2020-10-14
+            page-range: 454-477
+            doi: 10.1103/PhysRevB.102.165126
+            url: https://link.aps.org/doi/10.1103/PhysRevB.102.165126
+            parent:
+                type: Periodical
+                title: Physical Review B
+                volume: 102
+                issue: 16
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            to_ris(&entries),
+            "TY  - JOUR\n\
+             AU  - Doan, T. D.\n\
+             AU  - Haug, H.\n\
+             TI  - Kinetics and luminescence of the excitations\n\
+             JO  - Physical Review B\n\
+             VL  - 102\n\
+             IS  - 16\n\
+             SP  - 454\n\
+             EP  - 477\n\
+             PY  - 2020\n\
+             DO  - 10.1103/PhysRevB.102.165126\n\
+             UR  - https://link.aps.org/doi/10.1103/PhysRevB.102.165126\n\
+             ER  - \n"
+        );
+    }
+
+    #[test]
+    fn debug_table() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title:
+                value: Kinetics and luminescence of the excitations
+                translation: Kinetik
+            author: ["Doan, T. D.", "Haug, H."]
+            date: 2020-10-14
+            page-range: 454-477
+            parent:
+                type: Periodical
+                title: Physical Review B
+                volume: 102
+        "#;
+
+        let table = from_yaml_str(yaml).unwrap()[0].debug_table();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines[0], "key:        kinetics");
+        assert_eq!(lines[1], "type:       article");
+        assert!(lines.contains(&"author:     Doan, T. D.; Haug, H."));
+        assert!(lines.contains(&"page-range: 454-477"));
+        assert!(lines.contains(&"parent:"));
+        assert!(lines.contains(&"  title:  Physical Review B"));
+        assert!(lines.contains(&"  volume: 102"));
+        assert!(lines.contains(
+            &"title:      value: Kinetics and luminescence of the excitations, \
+               translation: Kinetik"
+        ));
+    }
 }
@@ -498,8 +498,17 @@ pub fn get_month_name(month: u8) -> Result<String, ()> {
 }
 
 /// Will get an english three-letter abbreviation for a zero-indexed month.
+/// The abbreviations in `overrides` take precedence and are used as-is.
 /// Returns an error if month out of range.
-pub fn get_month_abbr(month: u8, dot: bool) -> Result<String, ()> {
+pub fn get_month_abbr(
+    month: u8,
+    dot: bool,
+    overrides: Option<&[String; 12]>,
+) -> Result<String, ()> {
+    if let Some(abbr) = overrides.and_then(|o| o.get(month as usize)) {
+        return Ok(abbr.clone());
+    }
+
     let mut res = match month {
         0 => "Jan",
         1 => "Feb",
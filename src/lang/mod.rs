@@ -8,6 +8,31 @@ pub trait Case {
     fn apply(&self, string: &str) -> String;
 }
 
+/// Whether the string contains any characters that have a case at all.
+///
+/// Titles in scripts without case (e.g. Arabic, Hebrew, or CJK) are passed
+/// through case transformations unchanged.
+fn has_cased_chars(string: &str) -> bool {
+    string.chars().any(|c| c.is_lowercase() || c.is_uppercase())
+}
+
+/// Capitalizes the first cased character of a title, even if it is preceded
+/// by punctuation like quotation marks. The character is left alone if it
+/// does not start a word, e.g. in "3d printing".
+fn capitalize_first(title: String) -> String {
+    let first = title
+        .char_indices()
+        .find(|(_, c)| c.is_lowercase() || c.is_uppercase())
+        .filter(|&(i, _)| !title[..i].ends_with(char::is_alphanumeric));
+    match first {
+        Some((i, c)) if c.is_lowercase() => {
+            let rest = &title[i + c.len_utf8()..];
+            format!("{}{}{}", &title[..i], c.to_uppercase(), rest)
+        }
+        _ => title,
+    }
+}
+
 /// Rules for the title case transformation.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -69,6 +94,10 @@ impl Case for TitleCase {
             (false, false) => title,
         };
 
+        if !has_cased_chars(title) {
+            return title.to_string();
+        }
+
         // Do lowercase letters appear?
         // Otherwise, acronym detection will not be performed
         let mut has_lowercase = false;
@@ -202,7 +231,7 @@ impl Case for TitleCase {
             }        
         }
 
-        res
+        capitalize_first(res)
     }
 }
 
@@ -252,6 +281,10 @@ impl Case for SentenceCase {
     /// sentence ends or at the start, as well as (if the whitelist is used)
     /// select exceptions).
     fn apply(&self, title: &str) -> String {
+        if !has_cased_chars(title) {
+            return title.to_string();
+        }
+
         // Do lowercase letters appear?
         // Otherwise, acronym detection will not be performed
         let mut has_lowercase = false;
@@ -424,7 +457,7 @@ impl Case for SentenceCase {
             res.push_str(&c.to_lowercase().to_string());
         }
 
-        res
+        capitalize_first(res)
     }
 }
 
@@ -598,4 +631,33 @@ mod tests {
             title
         );
     }
+
+    #[test]
+    fn capitalize_first_cased_char() {
+        let title = TitleCase::new();
+        let sentence = SentenceCase::new();
+
+        assert_eq!("“The” Art of War", title.apply("“the” art of war"));
+        assert_eq!("“The” art of war", sentence.apply("“the” art of war"));
+        assert_eq!("'Tis the season", sentence.apply("'tis the season"));
+        assert_eq!("3d printing of bones", sentence.apply("3d printing of bones"));
+        assert_eq!("1st edition of it", sentence.apply("1st edition of it"));
+        assert_eq!("1st Edition of It", title.apply("1st edition of it"));
+
+        let acronym = "GRAL, a localization algorithm";
+        assert_eq!("GRAL, a Localization Algorithm", title.apply(acronym));
+        assert_eq!("GRAL, a localization algorithm", sentence.apply(acronym));
+        assert_eq!("“ISS” crew returns", sentence.apply("“ISS” crew returns"));
+    }
+
+    #[test]
+    fn uncased_scripts() {
+        let arabic = "مقدمة في علم الحاسوب: الجزء الأول";
+        let japanese = "日本語の自然言語処理。入門編";
+
+        for title in [arabic, japanese].iter() {
+            assert_eq!(*title, TitleCase::new().apply(title));
+            assert_eq!(*title, SentenceCase::new().apply(title));
+        }
+    }
 }
@@ -24,6 +24,8 @@ Language Association-style citation.
 - Other in-text citation styles
     - [Alphanumerical](style::Alphanumerical) (e. g. "Rass97")
     - [Author Title](style::AuthorTitle)
+- Debugging
+    - [Raw field dump](style::Raw) (e. g. "book | trial | Kafka, Franz | The Trial | 1998 |")
 
 # Usage
 
@@ -145,8 +147,8 @@ use thiserror::Error;
 use unic_langid::LanguageIdentifier;
 
 use types::{
-    Date, Duration, EntryType, FmtString, NumOrStr, Person, PersonRole, QualifiedUrl,
-    Title,
+    normalize_whitespace, Date, Duration, EntryType, FmtString, NumOrStr, Person,
+    PersonRole, QualifiedUrl, Title,
 };
 
 /// The data types that can possibly be held by the various fields of an
@@ -161,10 +163,15 @@ pub enum Value {
     /// A [FmtString] with which the user can override various
     /// automatic formatters.
     FmtString(FmtString),
+    /// A list of [FmtString]s, e.g. the publishers or locations of a
+    /// co-published book.
+    FmtStrings(Vec<FmtString>),
     /// A string to be reproduced as-is.
     Text(String),
     /// An integer.
     Integer(i64),
+    /// A boolean flag.
+    Bool(bool),
     /// A date, possibly only a year.
     Date(Date),
     /// A number of [Person]s.
@@ -185,6 +192,10 @@ pub enum Value {
     Language(LanguageIdentifier),
     /// Other [entries](Entry).
     Entries(Vec<Entry>),
+    /// The keys of other [entries](Entry) in the bibliography.
+    Keys(Vec<String>),
+    /// A number of [Unicode Language Identifiers](LanguageIdentifier).
+    Languages(Vec<LanguageIdentifier>),
 }
 
 /// A citable item in a bibliography.
@@ -233,22 +244,49 @@ impl Entry {
         let valid = match field.as_ref() {
             "parent" => matches!(value, Value::Entries(_)),
             "title" => matches!(value, Value::Title(_)),
-            "location" | "publisher" | "archive" | "archive-location" => {
+            "archive" | "archive-location" | "volume-title" => {
                 matches!(value, Value::FmtString(_))
             }
+            "publisher" | "location" => matches!(value, Value::FmtStrings(_)),
             "author" | "editor" => matches!(value, Value::Persons(_)),
-            "date" => matches!(value, Value::Date(_)),
+            "date" | "original-date" | "deposited-date" => {
+                matches!(value, Value::Date(_))
+            }
             "affiliated" => matches!(value, Value::PersonsWithRoles(_)),
-            "organization" | "issn" | "isbn" | "doi" | "serial-number" | "note" => {
+            "organization"
+            | "issn"
+            | "isbn"
+            | "doi"
+            | "registration-agency"
+            | "handle"
+            | "ark"
+            | "urn"
+            | "serial-number"
+            | "note"
+            | "status"
+            | "front-matter-pages"
+            | "season"
+            | "style"
+            | "access-note"
+            | "commit"
+            | "medium"
+            | "arxiv" => {
                 matches!(value, Value::Text(_))
             }
             "issue" | "edition" => matches!(value, Value::IntegerOrText(_)),
             "volume" | "page-range" => matches!(value, Value::Range(_)),
-            "volume-total" | "page-total" => matches!(value, Value::Integer(_)),
+            "volume-total" | "page-total" | "cited-by" => {
+                matches!(value, Value::Integer(_))
+            }
+            "early-access" | "anonymous" | "all-authors" | "dead-url" => {
+                matches!(value, Value::Bool(_))
+            }
             "time-range" => matches!(value, Value::TimeRange(_)),
             "runtime" => matches!(value, Value::Duration(_)),
-            "url" => matches!(value, Value::Url(_)),
+            "url" | "archive-url" => matches!(value, Value::Url(_)),
             "language" => matches!(value, Value::Language(_)),
+            "languages" => matches!(value, Value::Languages(_)),
+            "see-also" => matches!(value, Value::Keys(_)),
             _ => true,
         };
 
@@ -311,7 +349,16 @@ impl Entry {
     fields! {
         title: "title" => Title;
         authors: "author" => Vec<Person>, &[Person];
+        anonymous: "anonymous" => bool;
+        all_authors: "all-authors" => bool;
+    }
+
+    /// Get the shorthand of the entry's title for shortened citations and
+    /// running heads, if one was given.
+    pub fn short_title(&self) -> Option<&FmtString> {
+        self.title().and_then(|title| title.shorthand.as_ref())
     }
+
     fields! { @get date: "date" => &Date }
 
     /// Will recursively get a date off either the entry or any of its ancestors.
@@ -326,18 +373,48 @@ impl Entry {
     }
 
     fields! { @set date: "date" => Date }
+    fields! { original_date: "original-date" => Date }
+    fields! { deposited_date: "deposited-date" => Date }
     fields! {
         parents: "parent" => Vec<Entry>, &[Entry];
         editors: "editor" => Vec<Person>, &[Person];
         affiliated_persons: "affiliated" => Vec<(Vec<Person>, PersonRole)>, &[(Vec<Person>, PersonRole)];
-        publisher: "publisher" => FmtString;
-        location: "location" => FmtString;
+        publishers: "publisher" => Vec<FmtString>, &[FmtString];
+        locations: "location" => Vec<FmtString>, &[FmtString];
         organization: "organization";
         issue: "issue" => NumOrStr;
         volume: "volume" => std::ops::Range<i64>;
+        volume_title: "volume-title" => FmtString;
         volume_total: "volume-total" => i64;
+        cited_by: "cited-by" => i64;
+        see_also: "see-also" => Vec<String>, &[String];
         edition: "edition" => NumOrStr;
         page_range: "page-range" => std::ops::Range<i64>;
+        early_access: "early-access" => bool;
+    }
+
+    /// Get the first publisher of the entry. Co-publishers are only
+    /// available through [`Self::publishers`].
+    #[deprecated(note = "only returns the first publisher, use `publishers` instead")]
+    pub fn publisher(&self) -> Option<&FmtString> {
+        self.publishers().and_then(|publishers| publishers.first())
+    }
+
+    /// Set a single publisher in the `publisher` field.
+    pub fn set_publisher(&mut self, item: FmtString) {
+        self.set_publishers(vec![item]);
+    }
+
+    /// Get the first location of the entry. Further places of publication
+    /// are only available through [`Self::locations`].
+    #[deprecated(note = "only returns the first location, use `locations` instead")]
+    pub fn location(&self) -> Option<&FmtString> {
+        self.locations().and_then(|locations| locations.first())
+    }
+
+    /// Set a single location in the `location` field.
+    pub fn set_location(&mut self, item: FmtString) {
+        self.set_locations(vec![item]);
     }
 
     /// Get and parse the `page-total` field, falling back on `page-range` if
@@ -377,14 +454,29 @@ impl Entry {
 
     fields! { @set url: "url" => QualifiedUrl }
     fields! {
+        archive_url: "archive-url" => QualifiedUrl;
+        dead_url: "dead-url" => bool;
         doi: "doi";
+        registration_agency: "registration-agency";
+        handle: "handle";
+        ark: "ark";
+        urn: "urn";
+        arxiv: "arxiv";
         serial_number: "serial-number";
         isbn: "isbn";
         issn: "issn";
         language: "language" => LanguageIdentifier;
+        languages: "languages" => Vec<LanguageIdentifier>, &[LanguageIdentifier];
         archive: "archive" => FmtString;
         archive_location: "archive-location" => FmtString;
         note: "note";
+        status: "status";
+        front_matter_pages: "front-matter-pages";
+        season: "season";
+        style: "style";
+        access_note: "access-note";
+        commit: "commit";
+        medium: "medium";
     }
 }
 
@@ -400,6 +492,42 @@ impl Entry {
             .cloned()
             .collect()
     }
+
+    /// Collapses runs of whitespace in all textual fields of the entry and
+    /// its parents into single spaces and trims the fields. Non-breaking
+    /// spaces are kept as they are.
+    pub fn normalize(&mut self) {
+        for value in self.content.values_mut() {
+            value.normalize();
+        }
+    }
+}
+
+impl Value {
+    /// Normalizes the whitespace in the textual parts of the value.
+    fn normalize(&mut self) {
+        match self {
+            Value::Title(title) => {
+                title.canonical.normalize();
+                title.shorthand.iter_mut().for_each(FmtString::normalize);
+                title.translated.iter_mut().for_each(FmtString::normalize);
+            }
+            Value::FmtString(string) => string.normalize(),
+            Value::FmtStrings(strings) => {
+                strings.iter_mut().for_each(FmtString::normalize)
+            }
+            Value::Text(text) | Value::IntegerOrText(NumOrStr::Str(text)) => {
+                *text = normalize_whitespace(text);
+            }
+            Value::Persons(persons) => persons.iter_mut().for_each(Person::normalize),
+            Value::PersonsWithRoles(groups) => groups
+                .iter_mut()
+                .flat_map(|(persons, _)| persons.iter_mut())
+                .for_each(Person::normalize),
+            Value::Entries(entries) => entries.iter_mut().for_each(Entry::normalize),
+            _ => {}
+        }
+    }
 }
 
 #[cfg(feature = "biblatex")]
@@ -547,6 +675,31 @@ mod tests {
         assert_eq!(err.to_string(), "type `integer` is not allowed in field `author`",)
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn single_publisher() {
+        let mut entry = Entry::new("key", EntryType::Book);
+        entry.set_publisher(FmtString::new("Springer"));
+        assert_eq!(entry.publishers(), Some(&[FmtString::new("Springer")][..]));
+
+        entry.set_publishers(vec![
+            FmtString::new("Springer"),
+            FmtString::new("MIT Press"),
+        ]);
+        assert_eq!(entry.publisher(), Some(&FmtString::new("Springer")));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn single_location() {
+        let mut entry = Entry::new("key", EntryType::Book);
+        entry.set_location(FmtString::new("New York"));
+        assert_eq!(entry.locations(), Some(&[FmtString::new("New York")][..]));
+
+        entry.set_locations(vec![FmtString::new("New York"), FmtString::new("London")]);
+        assert_eq!(entry.location(), Some(&FmtString::new("New York")));
+    }
+
     macro_rules! select_all {
         ($select:expr, $entries:tt, [$($key:expr),* $(,)*] $(,)*) => {
             let keys = vec![ $( $key , )* ];
@@ -652,4 +805,30 @@ mod tests {
             ["a", "b", "c"]
         );
     }
+
+    #[test]
+    fn normalize() {
+        let yaml = r#"
+        messy:
+            type: Article
+            title: "  The\tquick   brown\u00a0fox "
+            author: ["  Doe ,  Jane  ", "Roe, Richard\t"]
+            parent:
+                type: Periodical
+                title: "Journal  of\n Foxes"
+        "#;
+
+        let mut entry = from_yaml_str(yaml).unwrap().remove(0);
+        entry.normalize();
+
+        assert_eq!(entry.title().unwrap().canonical.value, "The quick brown\u{a0}fox");
+
+        let authors = entry.authors().unwrap();
+        assert_eq!(authors[0].name, "Doe");
+        assert_eq!(authors[0].given_name.as_deref(), Some("Jane"));
+        assert_eq!(authors[1].given_name.as_deref(), Some("Richard"));
+
+        let parent = &entry.parents().unwrap()[0];
+        assert_eq!(parent.title().unwrap().canonical.value, "Journal of Foxes");
+    }
 }
@@ -10,6 +10,7 @@ use strum::{EnumVariantNames, VariantNames};
 use hayagriva::style::{
     Apa, AuthorTitle, BibliographyStyle as UsableBibliographyStyle, ChicagoAuthorDate,
     ChicagoNotes, Citation, CitationStyle as UsableCitationStyle, Database, Ieee, Mla,
+    Raw,
 };
 use hayagriva::Selector;
 use hayagriva::{
@@ -50,6 +51,7 @@ pub enum BibliographyStyle {
     Mla,
     Apa,
     Ieee,
+    Raw,
 }
 
 impl FromStr for BibliographyStyle {
@@ -64,6 +66,7 @@ impl FromStr for BibliographyStyle {
             "mla" => Ok(BibliographyStyle::Mla),
             "apa" => Ok(BibliographyStyle::Apa),
             "ieee" => Ok(BibliographyStyle::Ieee),
+            "raw" => Ok(BibliographyStyle::Raw),
             _ => Err("unknown style"),
         }
     }
@@ -77,6 +80,7 @@ pub enum CitationStyle {
     Alphanumerical,
     AuthorTitle,
     Numerical,
+    Vancouver,
 }
 
 impl FromStr for CitationStyle {
@@ -90,6 +94,7 @@ impl FromStr for CitationStyle {
             | "alpha" => Ok(CitationStyle::Alphanumerical),
             "author-title" => Ok(CitationStyle::AuthorTitle),
             "numerical" | "numeric" => Ok(CitationStyle::Numerical),
+            "vancouver" | "superscript" => Ok(CitationStyle::Vancouver),
             _ => Err("unknown style"),
         }
     }
@@ -319,6 +324,7 @@ fn main() {
                 BibliographyStyle::Apa => Box::new(Apa::new()),
                 BibliographyStyle::Ieee => Box::new(Ieee::new()),
                 BibliographyStyle::Mla => Box::new(Mla::new()),
+                BibliographyStyle::Raw => Box::new(Raw::new()),
             };
 
             let mut database = Database::new();
@@ -367,6 +373,11 @@ fn main() {
                 CitationStyle::AuthorTitle => Box::new(AuthorTitle::new()),
                 CitationStyle::ChicagoNote => Box::new(ChicagoNotes::default()),
                 CitationStyle::Numerical => Box::new(Numerical::new()),
+                CitationStyle::Vancouver => {
+                    let mut numerical = Numerical::new();
+                    numerical.superscript = true;
+                    Box::new(numerical)
+                }
             };
 
             let lines = if sub_matches.is_present("combined") {
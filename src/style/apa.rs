@@ -1,11 +1,13 @@
 use super::{
     alph_designator, delegate_titled_entry, format_range, name_list, name_list_straight,
     sorted_bibliography, BibliographyOrdering, BibliographyStyle, Database,
-    DisplayReference, DisplayString, Formatting, Record,
+    DisplayReference, DisplayString, Formatting, InitialAbbreviation, Record,
 };
 use crate::lang::en::{get_month_name, get_ordinal};
 use crate::lang::SentenceCase;
-use crate::types::{EntryType::*, FmtOptionExt, NumOrStr, Person, PersonRole};
+use crate::types::{
+    EntryType::*, FmtListExt, FmtOptionExt, NumOrStr, Person, PersonRole,
+};
 use crate::Entry;
 
 /// Bibliographies following APA guidance.
@@ -27,11 +29,31 @@ use crate::Entry;
 /// bibliographies.
 ///
 /// [apa]: https://apastyle.apa.org/style-grammar-guidelines/references
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct Apa {
     /// The configuration for sentence case formatting.
     pub sentence_case: SentenceCase,
+    /// The maximum number of names in a list of authors. Longer lists are
+    /// shortened to the names before the limit, an ellipsis, and the final
+    /// name. `None` always lists all names.
+    pub author_limit: Option<usize>,
+    /// The ellipsis that stands in for the names omitted from long lists.
+    pub ellipsis: String,
+    /// Which given names of editors, translators, and illustrators are
+    /// abbreviated. APA abbreviates all of them.
+    pub initial_abbreviation: InitialAbbreviation,
+}
+
+impl Default for Apa {
+    fn default() -> Self {
+        Self {
+            sentence_case: SentenceCase::default(),
+            author_limit: Some(20),
+            ellipsis: "...".to_string(),
+            initial_abbreviation: InitialAbbreviation::Always,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -111,86 +133,92 @@ impl<'s> SourceType<'s> {
     }
 }
 
-fn ampersand_list(names: Vec<String>) -> String {
-    let name_len = names.len() as i64;
-    let mut res = String::new();
+impl Apa {
+    /// Creates a new bibliography generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    for (index, name) in names.into_iter().enumerate() {
-        if index > 19 && name_len > 20 && (index as i64) != name_len - 1 {
-            // Element 20 or longer if longer than twenty and not last
-            continue;
+    /// Joins names with commas and an ampersand. Lists longer than the
+    /// [`author_limit`](Self::author_limit) are shortened to the names before
+    /// the limit, the ellipsis, and the final name.
+    fn ampersand_list(&self, mut names: Vec<String>) -> String {
+        let name_len = names.len();
+        if let Some(limit) = self.author_limit.filter(|&limit| name_len > limit) {
+            let last = names.pop().unwrap();
+            names.truncate(limit.saturating_sub(1));
+            names.push(format!("{} {}", self.ellipsis, last));
+            return names.join(", ");
         }
 
-        if index == 19 && name_len > 20 {
-            res += "... ";
-        } else {
+        let mut res = String::new();
+        for (index, name) in names.into_iter().enumerate() {
             res += &name;
-        }
 
-        if (index as i64) <= name_len - 2 {
-            res += ", ";
-        }
-        if (index as i64) == name_len - 2 {
-            res += "& ";
+            if index + 2 <= name_len {
+                res += ", ";
+            }
+            if index + 2 == name_len {
+                res += "& ";
+            }
         }
-    }
 
-    res
-}
+        res
+    }
 
-fn ed_vol_str(entry: &Entry, is_tv_show: bool) -> String {
-    let vstr = if let Some(vols) = entry.volume() {
-        if is_tv_show {
-            Some(format_range("Episode", "Episodes", &vols))
+    fn ed_vol_str(&self, entry: &Entry, is_tv_show: bool) -> String {
+        let vstr = if let Some(vols) = entry.volume() {
+            if is_tv_show {
+                Some(format_range("Episode", "Episodes", &vols))
+            } else {
+                Some(format_range("Vol.", "Vols.", &vols))
+            }
         } else {
-            Some(format_range("Vol.", "Vols.", &vols))
-        }
-    } else {
-        None
-    };
-
-    let ed = if is_tv_show { entry.issue() } else { entry.edition() };
+            None
+        };
 
-    let translator = entry.affiliated_with_role(PersonRole::Translator);
+        let ed = if is_tv_show { entry.issue() } else { entry.edition() };
 
-    let translator = if translator.is_empty() {
-        None
-    } else {
-        Some(format!("{}, Trans.", ampersand_list(name_list_straight(&translator))))
-    };
+        let translator = entry.affiliated_with_role(PersonRole::Translator);
 
-    let estr = if let Some(ed) = ed {
-        if is_tv_show {
-            Some(format!("Season {}", ed))
+        let translator = if translator.is_empty() {
+            None
         } else {
             Some(format!(
-                "{} ed.",
-                match ed {
-                    NumOrStr::Number(e) => get_ordinal(*e),
-                    NumOrStr::Str(s) => s.to_string(),
-                }
+                "{}, Trans.",
+                self.ampersand_list(name_list_straight(
+                    &translator,
+                    self.initial_abbreviation
+                ))
             ))
-        }
-    } else {
-        None
-    };
-
-    match (translator, estr, vstr) {
-        (Some(t), None, None) => format!(" ({})", t),
-        (Some(t), Some(e), None) => format!(" ({}; {})", t, e),
-        (Some(t), None, Some(v)) => format!(" ({}; {})", t, v),
-        (Some(t), Some(e), Some(v)) => format!(" ({}; {}, {})", t, e, v),
-        (None, None, None) => String::new(),
-        (None, Some(e), None) => format!(" ({})", e),
-        (None, None, Some(v)) => format!(" ({})", v),
-        (None, Some(e), Some(v)) => format!(" ({}, {})", e, v),
-    }
-}
+        };
 
-impl Apa {
-    /// Creates a new bibliography generator.
-    pub fn new() -> Self {
-        Self::default()
+        let estr = if let Some(ed) = ed {
+            if is_tv_show {
+                Some(format!("Season {}", ed))
+            } else {
+                Some(format!(
+                    "{} ed.",
+                    match ed {
+                        NumOrStr::Number(e) => get_ordinal(*e),
+                        NumOrStr::Str(s) => s.to_string(),
+                    }
+                ))
+            }
+        } else {
+            None
+        };
+
+        match (translator, estr, vstr) {
+            (Some(t), None, None) => format!(" ({})", t),
+            (Some(t), Some(e), None) => format!(" ({}; {})", t, e),
+            (Some(t), None, Some(v)) => format!(" ({}; {})", t, v),
+            (Some(t), Some(e), Some(v)) => format!(" ({}; {}, {})", t, e, v),
+            (None, None, None) => String::new(),
+            (None, Some(e), None) => format!(" ({})", e),
+            (None, None, Some(v)) => format!(" ({})", v),
+            (None, Some(e), Some(v)) => format!(" ({}, {})", e, v),
+        }
     }
 
     fn get_author(&self, entry: &Entry) -> (String, Vec<Person>) {
@@ -278,7 +306,7 @@ impl Apa {
                     .collect();
             }
 
-            let amps = ampersand_list(authors);
+            let amps = self.ampersand_list(authors);
             match role {
                 AuthorRole::Normal => amps,
                 AuthorRole::ExecutiveProducer if count == 1 => {
@@ -294,7 +322,7 @@ impl Apa {
             let res = if !eds.is_empty() {
                 format!(
                     "{} ({})",
-                    ampersand_list(name_list(&eds)),
+                    self.ampersand_list(name_list(&eds)),
                     if eds.len() == 1 { "Ed." } else { "Eds." }
                 )
             } else {
@@ -329,7 +357,7 @@ impl Apa {
                 .collect::<Vec<Person>>();
 
             if !affs.is_empty() {
-                details.push(format!("with {}", ampersand_list(name_list(&affs))));
+                details.push(format!("with {}", self.ampersand_list(name_list(&affs))));
                 pers_refs.extend(affs);
             }
         }
@@ -507,9 +535,9 @@ impl Apa {
                 new += res;
                 res = new;
             } else if (entry.volume().is_some() || entry.edition().is_some()) && book {
-                res += &ed_vol_str(entry, false);
+                res += &self.ed_vol_str(entry, false);
             } else if vid_match.apply(entry).is_some() {
-                res += &ed_vol_str(entry, true);
+                res += &self.ed_vol_str(entry, true);
             }
         }
 
@@ -519,7 +547,10 @@ impl Apa {
             if !illustrators.is_empty() {
                 items.push(format!(
                     "{}, Illus.",
-                    ampersand_list(name_list_straight(&illustrators))
+                    self.ampersand_list(name_list_straight(
+                        &illustrators,
+                        self.initial_abbreviation
+                    ))
                 ));
             }
 
@@ -527,7 +558,10 @@ impl Apa {
                 && !entry.authors().unwrap_or_default().is_empty()
             {
                 let editors = entry.editors().unwrap();
-                let amp_list = ampersand_list(name_list_straight(&editors));
+                let amp_list = self.ampersand_list(name_list_straight(
+                    editors,
+                    self.initial_abbreviation,
+                ));
                 if editors.len() == 1 {
                     items.push(format!("{}, Ed.", amp_list));
                 } else if editors.len() > 1 {
@@ -747,7 +781,7 @@ impl Apa {
                             true
                         }
                         _ => {
-                            res += &format!("{} (Eds.)", ampersand_list(names));
+                            res += &format!("{} (Eds.)", self.ampersand_list(names));
                             true
                         }
                     }
@@ -766,7 +800,7 @@ impl Apa {
                     comma = true;
 
                     if parent.volume().is_some() || parent.edition().is_some() {
-                        res += &ed_vol_str(parent, false);
+                        res += &self.ed_vol_str(parent, false);
                         res.push('.');
                         comma = false;
                     }
@@ -782,11 +816,11 @@ impl Apa {
                     res = new;
                 }
 
-                if parent.publisher().is_some() || parent.organization().is_some() {
+                if parent.publishers().is_some() || parent.organization().is_some() {
                     res.push(' ');
 
-                    if let Some(publisher) = parent.publisher() {
-                        res += &publisher.value;
+                    if let Some(publisher) = parent.publishers().joined("; ") {
+                        res += &publisher;
                     } else if let Some(organization) = parent.organization() {
                         res += organization;
                     }
@@ -808,7 +842,7 @@ impl Apa {
                         _ => {
                             res += &format!(
                                 "{} (Executive Producers)",
-                                ampersand_list(names)
+                                self.ampersand_list(names)
                             );
                             true
                         }
@@ -829,7 +863,7 @@ impl Apa {
 
                     if parent.volume().is_some() || parent.edition().is_some() {
                         res.push(' ');
-                        res += &ed_vol_str(entry, true);
+                        res += &self.ed_vol_str(entry, true);
                         res.push('.');
                     } else {
                         let lc = res.last().unwrap_or('a');
@@ -850,11 +884,11 @@ impl Apa {
                     res = new;
                 }
 
-                if parent.publisher().is_some() || parent.organization().is_some() {
+                if parent.publishers().is_some() || parent.organization().is_some() {
                     res.push(' ');
 
-                    if let Some(publisher) = parent.publisher() {
-                        res += &publisher.value;
+                    if let Some(publisher) = parent.publishers().joined("; ") {
+                        res += &publisher;
                     } else if let Some(organization) = parent.organization() {
                         res += organization;
                     }
@@ -875,46 +909,52 @@ impl Apa {
                 }
             }
             SourceType::ArtContainer(parent) => {
+                let parent_publisher = parent.publishers().joined("; ");
+                let publisher = entry.publishers().joined("; ");
                 let org = parent
                     .organization()
                     .or_else(|| parent.archive().value())
-                    .or_else(|| parent.publisher().value())
+                    .or(parent_publisher.as_deref())
                     .or_else(|| entry.organization())
                     .or_else(|| entry.archive().value())
-                    .or_else(|| entry.publisher().value());
+                    .or(publisher.as_deref());
 
                 if let Some(org) = org {
                     if let Some(loc) = parent
-                        .location()
-                        .or_else(|| parent.archive_location())
-                        .or_else(|| entry.location())
-                        .or_else(|| entry.archive_location())
+                        .locations()
+                        .joined("; ")
+                        .or_else(|| parent.archive_location().value().map(Into::into))
+                        .or_else(|| entry.locations().joined("; "))
+                        .or_else(|| entry.archive_location().value().map(Into::into))
                     {
-                        res += &format!("{}, {}.", org, loc.value);
+                        res += &format!("{}, {}.", org, loc);
                     } else {
                         res += org;
                     }
                 }
             }
             SourceType::StandaloneArt => {
+                let publisher = entry.publishers().joined("; ");
                 let org = entry
                     .organization()
                     .or_else(|| entry.archive().value())
-                    .or_else(|| entry.publisher().value());
+                    .or(publisher.as_deref());
 
                 if let Some(org) = org {
-                    if let Some(loc) =
-                        entry.location().or_else(|| entry.archive_location())
+                    if let Some(loc) = entry
+                        .locations()
+                        .joined("; ")
+                        .or_else(|| entry.archive_location().value().map(Into::into))
                     {
-                        res += &format!("{}, {}.", org, loc.value);
+                        res += &format!("{}, {}.", org, loc);
                     } else {
                         res += org;
                     }
                 }
             }
             SourceType::StandaloneWeb => {
-                let publisher =
-                    entry.publisher().value().or_else(|| entry.organization());
+                let publisher = entry.publishers().joined("; ");
+                let publisher = publisher.as_deref().or_else(|| entry.organization());
 
                 if let Some(publisher) = publisher {
                     let authors = entry.authors().unwrap_or_default();
@@ -963,12 +1003,12 @@ impl Apa {
                     false
                 };
 
-                if let Some(loc) = parent.location() {
+                if let Some(loc) = parent.locations().joined("; ") {
                     if comma {
                         res += ", ";
                     }
 
-                    res += &loc.value;
+                    res += &loc;
                 }
             }
             SourceType::GenericParent(parent) => {
@@ -982,9 +1022,9 @@ impl Apa {
                 }
             }
             SourceType::Generic => {
-                if entry.publisher().is_some() || entry.organization().is_some() {
-                    if let Some(publisher) = entry.publisher() {
-                        res += &publisher.value;
+                if entry.publishers().is_some() || entry.organization().is_some() {
+                    if let Some(publisher) = entry.publishers().joined("; ") {
+                        res += &publisher;
                     } else if let Some(organization) = entry.organization() {
                         res += organization;
                     }
@@ -1124,6 +1164,8 @@ mod tests {
     use url::Url;
 
     use super::Apa;
+    use crate::io::from_yaml_str;
+    use crate::style::{Database, InitialAbbreviation};
     use crate::types::Date;
     use crate::types::EntryType;
     use crate::types::Person;
@@ -1164,4 +1206,60 @@ mod tests {
             apa.get_retrieval_date(&entry, true).unwrap().value
         );
     }
+
+    #[test]
+    fn long_name_list() {
+        let p = (1..=25)
+            .map(|i| Person::from_strings(&[&format!("Author{}", i), "Ann"]).unwrap())
+            .collect();
+        let mut entry = Entry::new("test", EntryType::Article);
+        entry.set_authors(p);
+
+        let first: Vec<_> = (1..=19).map(|i| format!("Author{}, A.", i)).collect();
+        let mut apa = Apa::new();
+        assert_eq!(
+            format!("{}, ... Author25, A.", first.join(", ")),
+            apa.get_author(&entry).0
+        );
+
+        apa.author_limit = Some(3);
+        apa.ellipsis = "…".to_string();
+        assert_eq!("Author1, A., Author2, A., … Author25, A.", apa.get_author(&entry).0);
+    }
+
+    #[test]
+    fn initial_abbreviation() {
+        let yaml = r#"
+        trial:
+            type: Book
+            title: The trial
+            author: Kafka, Franz
+            date: 1998
+            edition: 2
+            affiliated:
+                - role: Translator
+                  names: ["Mitchell, Breon", "Muir, Willa"]
+        "#;
+
+        let format = |apa: &Apa, yaml: &str| {
+            let entries = from_yaml_str(yaml).unwrap();
+            let db = Database::from_entries(entries.iter());
+            db.reference(apa, "trial").unwrap().display.value
+        };
+
+        let mut apa = Apa::new();
+        assert_eq!(
+            format(&apa, yaml),
+            "Kafka, F. (1998). The trial (B. Mitchell, & W. Muir, Trans.; 2nd ed.)."
+        );
+
+        apa.initial_abbreviation = InitialAbbreviation::Never;
+        assert_eq!(format(&apa, yaml), "Kafka, F. (1998). The trial (Breon Mitchell, & Willa Muir, Trans.; 2nd ed.).");
+
+        apa.initial_abbreviation = InitialAbbreviation::AllButFirst;
+        assert_eq!(
+            format(&apa, yaml),
+            "Kafka, F. (1998). The trial (Mitchell, Breon, & W. Muir, Trans.; 2nd ed.)."
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use super::{
@@ -5,9 +6,9 @@ use super::{
     ChicagoConfig, Mode,
 };
 use crate::style::{
-    alph_designator, delegate_titled_entry, sorted_bibliography, BibliographyOrdering,
-    BibliographyStyle, Brackets, Citation, CitationStyle, Database, DisplayCitation,
-    DisplayReference, DisplayString, Record,
+    alph_designator, author_title_ord_custom, delegate_titled_entry, sorted_bibliography,
+    BibliographyOrdering, BibliographyStyle, Brackets, Citation, CitationStyle, Database,
+    DisplayCitation, DisplayReference, DisplayString, Record,
 };
 use crate::types::EntryType::*;
 use crate::types::Person;
@@ -51,6 +52,14 @@ pub struct ChicagoAuthorDate {
     /// Number of authors (equal or greater) for which the author
     /// list is truncated.
     pub et_al_limit: u8,
+    /// Whether the authors in citations are joined with "&" instead of "and".
+    /// The bibliography is configured separately through
+    /// [`ChicagoConfig::ampersand`].
+    pub citation_ampersand: bool,
+    /// Whether works by the same authors are listed chronologically in the
+    /// bibliography, with the names of all but the first replaced by a 3-em
+    /// dash. Only applies when the bibliography is ordered by author.
+    pub group_by_author: bool,
 }
 
 impl Default for ChicagoAuthorDate {
@@ -62,7 +71,12 @@ impl Default for ChicagoAuthorDate {
 impl ChicagoAuthorDate {
     /// Create a new author year citation formatter.
     pub fn new() -> Self {
-        Self { config: ChicagoConfig::new(), et_al_limit: 4 }
+        Self {
+            config: ChicagoConfig::new(),
+            et_al_limit: 4,
+            citation_ampersand: false,
+            group_by_author: false,
+        }
     }
 
     fn uniqueness<'a>(author: &Person, db: &Database<'a>) -> Uniqueness {
@@ -155,8 +169,13 @@ impl<'a> CitationStyle<'a> for ChicagoAuthorDate {
                     0
                 };
 
-                let mut list =
-                    and_list_opt(names, false, Some(self.et_al_limit.into()), et_al_auth);
+                let mut list = and_list_opt(
+                    names,
+                    false,
+                    Some(self.et_al_limit.into()),
+                    et_al_auth,
+                    if self.citation_ampersand { "&" } else { "and" },
+                );
 
                 if last_full && !list.ends_with('.') {
                     list.push('.');
@@ -224,12 +243,12 @@ impl<'a> CitationStyle<'a> for ChicagoAuthorDate {
                 s.push(designator);
             }
 
-            if let Some(supplement) = atomic.supplement {
+            if let Some(supplement) = atomic.full_supplement() {
                 if !supplement.ends_with(';') {
                     s += ", ";
                 }
 
-                s += supplement;
+                s += &supplement;
             }
 
             items.push(s);
@@ -268,7 +287,44 @@ impl<'a> BibliographyStyle<'a> for ChicagoAuthorDate {
             ))
         }
 
-        sorted_bibliography(items, ordering)
+        if !self.group_by_author || ordering != BibliographyOrdering::ByAuthor {
+            return sorted_bibliography(items, ordering);
+        }
+
+        // Works by the same authors are ordered chronologically instead of by
+        // their titles.
+        let year = |r: &DisplayReference| r.entry.date_any().map_or(i32::MAX, |d| d.year);
+        items.sort_by(|(a, a_auths), (b, b_auths)| {
+            a_auths
+                .is_empty()
+                .cmp(&b_auths.is_empty())
+                .then_with(|| a_auths.cmp(b_auths))
+                .then_with(|| {
+                    if a_auths.is_empty() {
+                        Ordering::Equal
+                    } else {
+                        year(a).cmp(&year(b))
+                    }
+                })
+                .then_with(|| {
+                    author_title_ord_custom(
+                        a.entry,
+                        b.entry,
+                        Some(a_auths),
+                        Some(b_auths),
+                    )
+                })
+        });
+
+        for i in 1..items.len() {
+            if !items[i].1.is_empty() && items[i].1 == items[i - 1].1 {
+                let record = &db.records[items[i].0.entry.key()];
+                items[i].0.display =
+                    bib_format.format_repeated(record.entry, record.disambiguation).0;
+            }
+        }
+
+        sorted_bibliography(items, BibliographyOrdering::ByInsertionOrder)
     }
 
     fn reference(&self, record: &Record<'a>) -> DisplayReference<'a> {
@@ -465,4 +521,61 @@ mod tests {
             "Doe, n.d."
         );
     }
+
+    #[test]
+    fn ampersands() {
+        let es = vec![date_author_entry(
+            "key",
+            vec![A("Laurenz", "Mädje"), A("Martin", "Haug")],
+            2020,
+        )];
+        let mut formatter = ChicagoAuthorDate {
+            citation_ampersand: true,
+            ..ChicagoAuthorDate::new()
+        };
+        let (citations, mut database) = Cs(&es);
+        assert_eq!(
+            database.citation(&mut formatter, &citations).display.value,
+            "Mädje & Haug 2020"
+        );
+
+        let bib = database.bibliography(&formatter, None);
+        assert!(bib[0].display.value.starts_with("Mädje, Laurenz, and Martin Haug."));
+
+        formatter.config.ampersand = true;
+        let bib = database.bibliography(&formatter, None);
+        assert!(bib[0].display.value.starts_with("Mädje, Laurenz, & Martin Haug."));
+    }
+
+    #[test]
+    fn group_by_author() {
+        let mut es = vec![
+            date_author_entry("c", vec![A("John", "Doe")], 2015),
+            date_author_entry("a", vec![A("John", "Doe")], 2010),
+            date_author_entry("b", vec![A("John", "Doe")], 2012),
+            date_author_entry("z", vec![A("Jane", "Roe")], 2000),
+        ];
+        for (entry, title) in es.iter_mut().zip(["Alpha", "Beta", "Gamma", "Delta"]) {
+            entry.set_title(Title::new(title));
+        }
+
+        let formatter =
+            ChicagoAuthorDate { group_by_author: true, ..ChicagoAuthorDate::new() };
+        let (_, database) = Cs(&es);
+        let bib: Vec<_> = database
+            .bibliography(&formatter, None)
+            .into_iter()
+            .map(|r| r.display.value)
+            .collect();
+
+        assert_eq!(
+            bib,
+            [
+                "Doe, John. 2010. Beta.",
+                "———. 2012. Gamma.",
+                "———. 2015. Alpha.",
+                "Roe, Jane. 2000. Delta.",
+            ]
+        );
+    }
 }
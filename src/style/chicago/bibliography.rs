@@ -3,14 +3,14 @@
 //! be set through the [`Bibliography`] struct.
 
 use super::{
-    and_list, entry_date, format_date, get_chunk_title, get_creators, get_info_element,
-    get_title, AuthorRole, ChicagoConfig, DateMode, Mode,
+    and_list, and_list_opt, entry_date, format_date, get_chunk_title, get_creators,
+    get_info_element, get_title, AuthorRole, ChicagoConfig, DateMode, Mode,
 };
 use crate::style::{
     abbreviate_publisher, alph_designator, chicago::web_creator, delegate_titled_entry,
     format_range, push_comma_quote_aware, DisplayString, Formatting,
 };
-use crate::types::{EntryType::*, FmtOptionExt, Person};
+use crate::types::{EntryType::*, FmtListExt, FmtOptionExt, Person};
 use crate::Entry;
 
 use unicode_segmentation::UnicodeSegmentation;
@@ -30,7 +30,7 @@ impl Bibliography {
         Self { config, mode }
     }
 
-    fn get_author(&self, entry: &Entry) -> String {
+    fn get_author(&self, entry: &Entry, repeated: bool) -> String {
         let (authors, add) = get_creators(entry);
         if authors.is_empty() {
             return String::new();
@@ -52,7 +52,12 @@ impl Bibliography {
                 name
             }
         });
-        let mut res = and_list(authors, true, self.config.et_al_limit);
+        let conjunction = if self.config.ampersand { "&" } else { "and" };
+        let mut res = if repeated {
+            "———".to_string()
+        } else {
+            and_list_opt(authors, true, self.config.et_al_limit, 0, conjunction)
+        };
 
         let add = match add {
             AuthorRole::Editor if count > 1 => "eds.",
@@ -101,9 +106,11 @@ impl Bibliography {
 
         let published_entry = select!(* > ("p":(*["publisher"]))).bound(entry, "p");
         if conference.is_none() {
+            // Only the first of several places of publication is named.
             if let Some(loc) = entry
-                .location()
-                .or_else(|| published_entry.and_then(|e| e.location()))
+                .locations()
+                .and_then(|l| l.first())
+                .or_else(|| published_entry.and_then(|e| e.locations()?.first()))
             {
                 if !res.is_empty() {
                     res += ", ";
@@ -165,25 +172,24 @@ impl Bibliography {
                 res += org;
             }
 
-            if let Some(loc) = conf.location() {
+            if let Some(loc) = conf.locations().and_then(|l| l.first()) {
                 res += ", ";
                 res += &loc.value;
             }
         } else if let Some(publisher) = entry
-            .publisher()
-            .value()
-            .or_else(|| published_entry.map(|e| e.publisher().value().unwrap()))
+            .publishers()
+            .joined("; ")
+            .or_else(|| published_entry.and_then(|e| e.publishers().joined("; ")))
             .or_else(|| {
                 if matches!(&entry.entry_type, Report | Thesis)
                     || (matches!(&entry.entry_type, Case | Legislation)
                         && entry.serial_number().is_some())
                 {
-                    entry.organization()
+                    entry.organization().map(Into::into)
                 } else {
                     None
                 }
             })
-            .map(Into::into)
             .or_else(|| {
                 if entry.entry_type == Reference && entry.volume().is_none() {
                     entry.authors().map(|a| {
@@ -231,16 +237,17 @@ impl Bibliography {
             items.extend(
                 entry
                     .organization()
-                    .or_else(|| entry.publisher().value())
-                    .or_else(|| parent.and_then(|p| p.organization()))
-                    .or_else(|| parent.and_then(|p| p.publisher().value()))
-                    .map(Into::into),
+                    .map(Into::into)
+                    .or_else(|| entry.publishers().joined("; "))
+                    .or_else(|| parent.and_then(|p| p.organization()).map(Into::into))
+                    .or_else(|| parent.and_then(|p| p.publishers().joined("; "))),
             );
 
             items.extend(
                 entry
-                    .location()
-                    .or_else(|| parent.and_then(|p| p.location()))
+                    .locations()
+                    .and_then(|l| l.first())
+                    .or_else(|| parent.and_then(|p| p.locations()?.first()))
                     .value()
                     .map(Into::into),
             );
@@ -265,17 +272,36 @@ impl Bibliography {
 
     /// Format a reference.
     pub fn format(
+        &self,
+        entry: &Entry,
+        num: Option<usize>,
+    ) -> (DisplayString, Vec<Person>) {
+        self.format_with_author(entry, num, false)
+    }
+
+    /// Format a reference whose authors are the same as those of the
+    /// preceding reference. Their names are replaced with a 3-em dash.
+    pub fn format_repeated(
+        &self,
+        entry: &Entry,
+        num: Option<usize>,
+    ) -> (DisplayString, Vec<Person>) {
+        self.format_with_author(entry, num, true)
+    }
+
+    fn format_with_author(
         &self,
         mut entry: &Entry,
         num: Option<usize>,
+        repeated: bool,
     ) -> (DisplayString, Vec<Person>) {
         entry = delegate_titled_entry(entry);
 
         let mut res: DisplayString = if entry.entry_type != Reference
-            || entry.publisher().is_some()
+            || entry.publishers().is_some()
             || entry.volume().is_some()
         {
-            self.get_author(entry).into()
+            self.get_author(entry, repeated).into()
         } else {
             DisplayString::new()
         };
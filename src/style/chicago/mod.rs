@@ -8,8 +8,8 @@ pub mod notes;
 use isolang::Language;
 
 use super::{
-    format_range, omit_initial_articles, push_comma_quote_aware, DisplayString,
-    Formatting,
+    format_range, name_list_straight, omit_initial_articles, push_comma_quote_aware,
+    DisplayString, Formatting, InitialAbbreviation,
 };
 use crate::lang::{en::get_month_name, en::get_ordinal, SentenceCase, TitleCase};
 use crate::types::{
@@ -116,7 +116,11 @@ fn is_formally_published(entry: &Entry) -> bool {
 }
 
 /// Common configuration options for the Chicago styles.
+///
+/// Start from [`ChicagoConfig::new`] and change the fields you need, as more
+/// options may be added in the future.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ChicagoConfig {
     /// If there is greater or equal to this number of authors, they will be
     /// abbreviated using et. al. after the first name.
@@ -127,6 +131,12 @@ pub struct ChicagoConfig {
     pub title_case: TitleCase,
     /// The sentence case configuration.
     pub sentence_case: SentenceCase,
+    /// Whether the authors in the bibliography are joined with "&" instead
+    /// of "and".
+    pub ampersand: bool,
+    /// Which given names of editors and compilers are abbreviated. Chicago
+    /// spells all of them out.
+    pub initial_abbreviation: InitialAbbreviation,
 }
 
 impl Default for ChicagoConfig {
@@ -136,6 +146,8 @@ impl Default for ChicagoConfig {
             url_access_date: ChicagoAccessDateVisibility::default(),
             title_case: TitleCase::new(),
             sentence_case: SentenceCase::new(),
+            ampersand: false,
+            initial_abbreviation: InitialAbbreviation::Never,
         }
     }
 }
@@ -218,6 +230,7 @@ pub(super) fn and_list_opt(
     oxford: bool,
     et_al_limit: Option<usize>,
     et_al_items: usize,
+    conjunction: &str,
 ) -> String {
     let names = names.into_iter().collect::<Vec<_>>();
     let name_len = names.len();
@@ -240,7 +253,8 @@ pub(super) fn and_list_opt(
             res.push(' ');
         }
         if index + 2 == name_len && !(threshold > 0 && name_len >= threshold) {
-            res += "and ";
+            res += conjunction;
+            res.push(' ');
         }
     }
 
@@ -259,7 +273,7 @@ pub(super) fn and_list(
     oxford: bool,
     et_al_limit: Option<usize>,
 ) -> String {
-    and_list_opt(names, oxford, et_al_limit, 0)
+    and_list_opt(names, oxford, et_al_limit, 0, "and")
 }
 
 fn get_title(
@@ -311,8 +325,7 @@ fn get_title(
 
             if parent.authors().is_none() {
                 if let Some(eds) = parent.editors() {
-                    let ed_names =
-                        eds.iter().map(|p| p.given_first(false)).collect::<Vec<_>>();
+                    let ed_names = name_list_straight(eds, common.initial_abbreviation);
 
                     let mut local =
                         if ed_names.len() > 1 { "eds. " } else { "ed. " }.to_string();
@@ -376,7 +389,7 @@ pub(super) fn get_chunk_title(
     let np = entry.entry_type == Newspaper;
 
     if short {
-        if let Some(title) = entry.title().map(|t| shorthand(t)) {
+        if let Some(title) = entry.title().map(shorthand) {
             res += &if entry.entry_type == Entry {
                 title.value
             } else {
@@ -464,20 +477,27 @@ fn shorthand(title: &Title) -> FmtString {
     if let Some(sh) = title.shorthand.as_ref() {
         sh.clone()
     } else {
-        let mut fmt = FmtString::new(omit_initial_articles(&title.canonical.value))
+        let mut fmt = FmtString::new(short_form(&title.canonical.value))
             .verbatim(title.canonical.verbatim);
 
         if let Some(tc) = title.canonical.title_case.as_ref() {
-            fmt = fmt.title_case(omit_initial_articles(tc))
+            fmt = fmt.title_case(short_form(tc))
         }
         if let Some(sc) = title.canonical.sentence_case.as_ref() {
-            fmt = fmt.sentence_case(omit_initial_articles(sc))
+            fmt = fmt.sentence_case(short_form(sc))
         }
 
         fmt
     }
 }
 
+/// Shortens a title without a shorthand by omitting initial articles and the
+/// subtitle.
+fn short_form(title: &str) -> String {
+    let title = omit_initial_articles(title);
+    title.split(':').next().unwrap_or_default().trim_end().to_string()
+}
+
 fn web_creator(
     entry: &Entry,
     invert_first: bool,
@@ -631,7 +651,7 @@ fn get_info_element(
         .bound(entry, "p");
 
         if orig_entry.authors().is_some() && ed_match != edited_book {
-            let ed_names = eds.iter().map(|p| p.given_first(false)).collect::<Vec<_>>();
+            let ed_names = name_list_straight(eds, common.initial_abbreviation);
 
             let mut local = if capitals && ed_match != Some(orig_entry) {
                 "Edited by "
@@ -655,7 +675,7 @@ fn get_info_element(
         let mut local = get_chunk_title(journal, false, true, common);
 
         if let Some(paper) = newspaper {
-            if let Some(location) = paper.location() {
+            if let Some(location) = paper.locations().and_then(|l| l.first()) {
                 if !local.is_empty() {
                     local.push(' ');
                 }
@@ -718,8 +738,7 @@ fn get_info_element(
         if (orig_entry.authors().is_some() || orig_entry.entry_type == Reference)
             || orig_entry.editors().is_some()
         {
-            let comp_names =
-                comp.into_iter().map(|p| p.given_first(false)).collect::<Vec<_>>();
+            let comp_names = name_list_straight(&comp, common.initial_abbreviation);
 
             let mut local = if capitals {
                 "Compiled by "
@@ -794,8 +813,7 @@ fn get_info_element(
             if let Some(eds) = orig_entry.editors() {
                 push_comma_quote_aware(&mut title.value, ',', true);
                 title.commit_formats();
-                let ed_names =
-                    eds.iter().map(|p| p.given_first(false)).collect::<Vec<_>>();
+                let ed_names = name_list_straight(eds, common.initial_abbreviation);
 
                 let mut local = if capitals {
                     "edited by "
@@ -11,7 +11,7 @@ use crate::style::{
     CitationStyle, Database, DisplayCitation, DisplayReference, DisplayString,
     Formatting, Record,
 };
-use crate::types::{EntryType::*, FmtOptionExt};
+use crate::types::{EntryType::*, FmtListExt, FmtOptionExt};
 use crate::Entry;
 
 /// Verbosity of Chicago _Notes_.
@@ -191,9 +191,11 @@ impl<'a> ChicagoNotes<'a> {
         };
 
         let published_entry = select!(* > ("p":(*["publisher"]))).bound(entry, "p");
+        // Only the first of several places of publication is named.
         if let Some(loc) = entry
-            .location()
-            .or_else(|| published_entry.and_then(|e| e.location()))
+            .locations()
+            .and_then(|l| l.first())
+            .or_else(|| published_entry.and_then(|e| e.locations()?.first()))
         {
             if !res.is_empty() {
                 res += ", ";
@@ -253,25 +255,24 @@ impl<'a> ChicagoNotes<'a> {
                 res += &conf_name;
             }
 
-            if let Some(loc) = conf.location() {
+            if let Some(loc) = conf.locations().and_then(|l| l.first()) {
                 res += ", ";
                 res += &loc.value;
             }
         } else if let Some(publisher) = entry
-            .publisher()
-            .value()
-            .or_else(|| published_entry.map(|e| e.publisher().value().unwrap()))
+            .publishers()
+            .joined("; ")
+            .or_else(|| published_entry.and_then(|e| e.publishers().joined("; ")))
             .or_else(|| {
                 if matches!(&entry.entry_type, Report | Thesis)
                     || (matches!(&entry.entry_type, Case | Legislation)
                         && entry.serial_number().is_some())
                 {
-                    entry.organization()
+                    entry.organization().map(Into::into)
                 } else {
                     None
                 }
             })
-            .map(Into::into)
             .or_else(|| {
                 if entry.entry_type == Reference && entry.volume().is_none() {
                     entry.authors().map(|a| {
@@ -315,16 +316,17 @@ impl<'a> ChicagoNotes<'a> {
             items.extend(
                 entry
                     .organization()
-                    .or_else(|| entry.publisher().value())
-                    .or_else(|| parent.and_then(|p| p.organization()))
-                    .or_else(|| parent.and_then(|p| p.publisher().value()))
-                    .map(Into::into),
+                    .map(Into::into)
+                    .or_else(|| entry.publishers().joined("; "))
+                    .or_else(|| parent.and_then(|p| p.organization()).map(Into::into))
+                    .or_else(|| parent.and_then(|p| p.publishers().joined("; "))),
             );
 
             items.extend(
                 entry
-                    .location()
-                    .or_else(|| parent.and_then(|p| p.location()))
+                    .locations()
+                    .and_then(|l| l.first())
+                    .or_else(|| parent.and_then(|p| p.locations()?.first()))
                     .value()
                     .map(Into::into),
             );
@@ -365,7 +367,7 @@ impl<'a> ChicagoNotes<'a> {
             "Ibid".into()
         } else if (!web_thing
             && (entry.entry_type != Reference
-                || entry.publisher().is_some()
+                || entry.publishers().is_some()
                 || entry.volume().is_some()))
             || short
         {
@@ -469,7 +471,7 @@ impl<'a> ChicagoNotes<'a> {
             res += get_chunk_title(entry, false, true, &self.config);
         }
 
-        if let Some(supplement) = citation.supplement {
+        if let Some(supplement) = citation.full_supplement() {
             if !res.is_empty() {
                 if colon {
                     res.push(':');
@@ -479,7 +481,7 @@ impl<'a> ChicagoNotes<'a> {
                 res.push(' ');
             }
 
-            res += supplement;
+            res += &supplement;
         } else if let Some(pr) = entry.page_range() {
             if !res.is_empty() {
                 if colon {
@@ -659,3 +661,74 @@ impl<'a> BibliographyStyle<'a> for ChicagoNotes<'a> {
         BibliographyOrdering::ByAuthor
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ChicagoNoteStyle, ChicagoNotes};
+    use crate::io::from_yaml_str;
+    use crate::style::{Citation, Database, InitialAbbreviation};
+
+    #[test]
+    fn short_title() {
+        let yaml = r#"
+        decline:
+            type: Book
+            author: Gibbon, Edward
+            title:
+                value: The history of the decline and fall of the Roman Empire
+                shorthand: Decline and Fall
+            date: 1776
+        geology:
+            type: Book
+            author: Lyell, Charles
+            title: "Principles of geology: being an attempt to explain the former changes"
+            date: 1830
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(entries[0].short_title().unwrap().value, "Decline and Fall");
+        assert!(entries[1].short_title().is_none());
+
+        let mut db = Database::from_entries(entries.iter());
+        let mut notes = ChicagoNotes::new();
+        notes.style = ChicagoNoteStyle::Short;
+
+        let mut cite = |i: usize| {
+            db.citation(&mut notes, &[Citation::new(&entries[i], None)])
+                .display
+                .value
+        };
+
+        assert_eq!(cite(0), "Gibbon, Decline and Fall.");
+        assert_eq!(cite(1), "Lyell, Principles of Geology.");
+    }
+
+    #[test]
+    fn initial_abbreviation() {
+        let yaml = r#"
+        letters:
+            type: Book
+            title: Collected letters
+            author: Woolf, Virginia
+            editor: ["Nicolson, Nigel", "Trautmann, Joanne"]
+            date: 1975
+            publisher: Hogarth Press
+            location: London
+        "#;
+
+        let format = |notes: &ChicagoNotes, yaml: &str| {
+            let entries = from_yaml_str(yaml).unwrap();
+            let db = Database::from_entries(entries.iter());
+            db.reference(notes, "letters").unwrap().display.value
+        };
+
+        let mut notes = ChicagoNotes::new();
+        assert_eq!(format(&notes, yaml), "Woolf, Virginia. Collected Letters. Eds. Nigel Nicolson and Joanne Trautmann. London: Hogarth Press, 1975.");
+
+        notes.config.initial_abbreviation = InitialAbbreviation::Always;
+        assert_eq!(format(&notes, yaml), "Woolf, Virginia. Collected Letters. Eds. N. Nicolson and J. Trautmann. London: Hogarth Press, 1975.");
+
+        notes.config.initial_abbreviation = InitialAbbreviation::AllButFirst;
+        assert_eq!(format(&notes, yaml), "Woolf, Virginia. Collected Letters. Eds. Nicolson, Nigel and J. Trautmann. London: Hogarth Press, 1975.");
+    }
+}
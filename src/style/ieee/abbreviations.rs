@@ -1,4 +1,4 @@
-//! IEEE journal and conference abbreviations.
+//! IEEE journal, conference, and place name abbreviations.
 
 macro_rules! journal_abbrv {
     ($count:expr, [$($value:expr => $abbr:expr),* $(,)*] $(,)*) => {
@@ -351,3 +351,122 @@ pub(super) fn abbreviate_journal(title: &str) -> String {
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+/// The names of US states and their two-letter postal codes.
+const US_STATES: [(&str, &str); 51] = [
+    ("alabama", "AL"),
+    ("alaska", "AK"),
+    ("arizona", "AZ"),
+    ("arkansas", "AR"),
+    ("california", "CA"),
+    ("colorado", "CO"),
+    ("connecticut", "CT"),
+    ("delaware", "DE"),
+    ("district of columbia", "DC"),
+    ("florida", "FL"),
+    ("georgia", "GA"),
+    ("hawaii", "HI"),
+    ("idaho", "ID"),
+    ("illinois", "IL"),
+    ("indiana", "IN"),
+    ("iowa", "IA"),
+    ("kansas", "KS"),
+    ("kentucky", "KY"),
+    ("louisiana", "LA"),
+    ("maine", "ME"),
+    ("maryland", "MD"),
+    ("massachusetts", "MA"),
+    ("michigan", "MI"),
+    ("minnesota", "MN"),
+    ("mississippi", "MS"),
+    ("missouri", "MO"),
+    ("montana", "MT"),
+    ("nebraska", "NE"),
+    ("nevada", "NV"),
+    ("new hampshire", "NH"),
+    ("new jersey", "NJ"),
+    ("new mexico", "NM"),
+    ("new york", "NY"),
+    ("north carolina", "NC"),
+    ("north dakota", "ND"),
+    ("ohio", "OH"),
+    ("oklahoma", "OK"),
+    ("oregon", "OR"),
+    ("pennsylvania", "PA"),
+    ("rhode island", "RI"),
+    ("south carolina", "SC"),
+    ("south dakota", "SD"),
+    ("tennessee", "TN"),
+    ("texas", "TX"),
+    ("utah", "UT"),
+    ("vermont", "VT"),
+    ("virginia", "VA"),
+    ("washington", "WA"),
+    ("west virginia", "WV"),
+    ("wisconsin", "WI"),
+    ("wyoming", "WY"),
+];
+
+/// Spellings of country names and the form IEEE prints them in.
+const COUNTRIES: [(&str, &str); 10] = [
+    ("united states", "USA"),
+    ("united states of america", "USA"),
+    ("us", "USA"),
+    ("u.s.", "USA"),
+    ("u.s.a.", "USA"),
+    ("usa", "USA"),
+    ("united kingdom", "U.K."),
+    ("uk", "U.K."),
+    ("u.k.", "U.K."),
+    ("great britain", "U.K."),
+];
+
+/// State codes that are also ISO 3166 country codes and state names that are
+/// also country names. A location that ends in one of them is not assumed to
+/// be in the United States, e.g. "Stuttgart, DE" or "Tbilisi, Georgia".
+const AMBIGUOUS_STATES: [&str; 27] = [
+    "al", "ar", "az", "ca", "co", "de", "ga", "georgia", "id", "il", "in", "ky", "la",
+    "ma", "md", "me", "mn", "mo", "ms", "mt", "nc", "ne", "pa", "sc", "sd", "tn", "va",
+];
+
+/// Brings a location into the "City, ST, Country" form, e.g. "Boston,
+/// Massachusetts" becomes "Boston, MA, USA".
+///
+/// A state is only recognized as the last component of the location or right
+/// before a spelling of the United States. Locations whose last component
+/// could also be a country are left unchanged.
+pub(super) fn normalize_location(location: &str) -> String {
+    let parts: Vec<&str> = location.split(',').map(str::trim).collect();
+    let last = parts.len() - 1;
+    if last == 0 {
+        return location.to_string();
+    }
+
+    let us_state = |part: &str| {
+        let lower = part.to_lowercase();
+        US_STATES
+            .iter()
+            .find(|&&(name, code)| name == lower || code == part)
+            .map(|&(_, code)| code)
+    };
+
+    let mut normalized: Vec<String> = parts.iter().map(ToString::to_string).collect();
+    let lower = parts[last].to_lowercase();
+    if let Some(&(_, abbr)) = COUNTRIES.iter().find(|&&(name, _)| name == lower) {
+        normalized[last] = abbr.to_string();
+        if abbr == "USA" && last > 1 {
+            if let Some(code) = us_state(parts[last - 1]) {
+                normalized[last - 1] = code.to_string();
+            }
+        }
+    } else if AMBIGUOUS_STATES.contains(&lower.as_str()) {
+        return location.to_string();
+    } else if let Some(code) = us_state(parts[last]) {
+        normalized[last] = code.to_string();
+        normalized.push("USA".to_string());
+    } else {
+        return location.to_string();
+    }
+
+    normalized.join(", ")
+}
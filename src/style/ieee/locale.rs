@@ -0,0 +1,117 @@
+//! Localized terms of the IEEE style.
+
+use std::collections::HashMap;
+
+use unic_langid::LanguageIdentifier;
+
+/// The terms that the [IEEE style](super::Ieee) localizes.
+///
+/// The [default](Self::default) terms are English. Built-in terms for other
+/// languages can be obtained with [`Self::from_language`] and individual
+/// terms can be overridden afterwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IeeeLocale {
+    /// Custom abbreviations for the twelve months, starting with January.
+    /// They replace the default abbreviations like "Sep." verbatim.
+    pub month_abbreviations: Option<[String; 12]>,
+    /// The opening and closing quotation marks around the titles of articles,
+    /// chapters, and other parts of larger works. They include any spacing
+    /// the language puts inside of them, e.g. narrow no-break spaces in
+    /// French.
+    pub quotes: (String, String),
+    /// Localized annotations for the languages of entries, keyed by their
+    /// ISO 639-1 code, e.g. "auf Deutsch" for "de". Other languages are
+    /// annotated with their English name, e.g. "in German".
+    pub language_annotations: HashMap<String, String>,
+    /// The conjunction that joins the languages of multilingual works, e.g.
+    /// "and" in "in English and French".
+    pub language_conjunction: String,
+}
+
+impl Default for IeeeLocale {
+    fn default() -> Self {
+        Self {
+            month_abbreviations: None,
+            quotes: ("“".into(), "”".into()),
+            language_annotations: HashMap::new(),
+            language_conjunction: "and".into(),
+        }
+    }
+}
+
+impl IeeeLocale {
+    /// Creates the English terms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the built-in terms for a language.
+    ///
+    /// German and French come with month abbreviations, annotations for
+    /// common languages, and a conjunction. Other languages only get their
+    /// quotation marks, e.g. «…» for Italian, and use English terms otherwise.
+    pub fn from_language(language: &LanguageIdentifier) -> Self {
+        let (months, annotations, conjunction): (Option<[&str; 12]>, &[_], _) =
+            match language.language.as_str() {
+                "de" => (Some(GERMAN_MONTHS), &GERMAN_ANNOTATIONS, "und"),
+                "fr" => (Some(FRENCH_MONTHS), &FRENCH_ANNOTATIONS, "et"),
+                _ => (None, &[], "and"),
+            };
+
+        Self {
+            month_abbreviations: months.map(|months| months.map(String::from)),
+            quotes: locale_quotes(language),
+            language_annotations: annotations
+                .iter()
+                .map(|&(code, annotation)| (code.to_string(), annotation.to_string()))
+                .collect(),
+            language_conjunction: conjunction.into(),
+        }
+    }
+}
+
+const GERMAN_MONTHS: [&str; 12] = [
+    "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sep.", "Okt.",
+    "Nov.", "Dez.",
+];
+
+const FRENCH_MONTHS: [&str; 12] = [
+    "janv.", "févr.", "mars", "avr.", "mai", "juin", "juill.", "août", "sept.", "oct.",
+    "nov.", "déc.",
+];
+
+const GERMAN_ANNOTATIONS: [(&str, &str); 8] = [
+    ("de", "auf Deutsch"),
+    ("en", "auf Englisch"),
+    ("es", "auf Spanisch"),
+    ("fr", "auf Französisch"),
+    ("it", "auf Italienisch"),
+    ("ja", "auf Japanisch"),
+    ("ru", "auf Russisch"),
+    ("zh", "auf Chinesisch"),
+];
+
+const FRENCH_ANNOTATIONS: [(&str, &str); 8] = [
+    ("de", "en allemand"),
+    ("en", "en anglais"),
+    ("es", "en espagnol"),
+    ("fr", "en français"),
+    ("it", "en italien"),
+    ("ja", "en japonais"),
+    ("ru", "en russe"),
+    ("zh", "en chinois"),
+];
+
+/// The opening and closing quotation marks of a locale.
+fn locale_quotes(locale: &LanguageIdentifier) -> (String, String) {
+    let swiss = matches!(locale.region, Some(r) if r.as_str() == "CH");
+    let (open, close) = match locale.language.as_str() {
+        "de" if swiss => ("«", "»"),
+        "de" | "cs" | "sk" => ("„", "“"),
+        "fr" => ("«\u{202F}", "\u{202F}»"),
+        "it" | "es" | "ru" => ("«", "»"),
+        _ => ("“", "”"),
+    };
+    (open.into(), close.into())
+}
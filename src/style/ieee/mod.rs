@@ -1,17 +1,26 @@
 mod abbreviations;
+mod locale;
 
+pub use locale::IeeeLocale;
+
+use std::fmt;
+use std::sync::Arc;
 use std::vec;
 
 use isolang::Language;
+use unic_langid::LanguageIdentifier;
 
 use super::{
-    alph_designator, format_range, name_list_straight, push_comma_quote_aware,
+    alph_designator, format_range, name_list_straight, push_comma_quote_aware_with,
     sorted_bibliography, BibliographyOrdering, BibliographyStyle, Database,
-    DisplayReference, DisplayString, Formatting, Record,
+    DisplayReference, DisplayString, FormatEvent, Formatting, InitialAbbreviation,
+    Record,
 };
 use crate::lang::{en, SentenceCase, TitleCase};
-use crate::types::{Date, EntryType::*, FmtOptionExt, NumOrStr, Person, PersonRole};
-use crate::Entry;
+use crate::types::{
+    Date, EntryType::*, FmtListExt, NumOrStr, Person, PersonRole, QualifiedUrl,
+};
+use crate::{Entry, Selector};
 
 /// Bibliographies following IEEE guidance.
 ///
@@ -23,8 +32,8 @@ use crate::Entry;
 ///   7, Jun. 19, 2019, doi: 10.1109/ACCESS.2019.
 /// - R. Roundy, “Report on practices related to demand forecasting for
 ///   semiconductor products,” Cornell University Operations Research and
-///   Industrial Engineering, Jul. 2001. Accessed: Jan. 7, 2021. \[Online\].
-///   Available: <https://hdl.handle.net/1813/9174>
+///   Industrial Engineering, Jul. 2001. \[Online\]. Available:
+///   <https://hdl.handle.net/1813/9174>
 ///
 /// # Reference
 /// See the following documents for details on how the Institute of Electrical
@@ -45,8 +54,387 @@ pub struct Ieee {
     /// How many authors have to be there for their list to be abbreviated with
     /// "et al."
     pub et_al_threshold: Option<u32>,
+    /// How many names are printed before "et al." once the list is
+    /// abbreviated.
+    pub et_al_names: u32,
+    /// How many editors have to be there for their list to be abbreviated
+    /// with "et al."
+    pub editor_et_al_threshold: Option<u32>,
     /// Whether to use abbreviations for journal titles
     pub abbreviate_journals: bool,
+    /// Whether to append the original publication date of reprints and
+    /// translations after the date of the cited edition.
+    pub show_original_date: bool,
+    /// Hosts of persistent identifiers (e.g. DOIs and handles). Access dates
+    /// are omitted for URLs on these hosts or their subdomains unless
+    /// configured otherwise through [`access_dates`](Self::access_dates).
+    pub persistent_hosts: Vec<String>,
+    /// When to print the access dates of URLs.
+    pub access_dates: IeeeAccessDateVisibility,
+    /// Whether to capitalize the "In" before container titles. It is always
+    /// capitalized when it begins the reference.
+    pub capitalize_in: bool,
+    /// Maximum number of characters of an entry's title. Longer titles are
+    /// cut at a word boundary and end with an ellipsis. `None` means the
+    /// titles are never truncated.
+    pub max_title_chars: Option<usize>,
+    /// Whether to italicize "et al." in author lists.
+    pub italic_et_al: bool,
+    /// Whether to emit italic spans at all. If disabled, the text of
+    /// container titles and "et al." is still printed, but the references
+    /// contain no italic formatting. This overrides
+    /// [`italic_et_al`](Self::italic_et_al).
+    pub italics: bool,
+    /// Whether the editors of works that also have authors are introduced
+    /// with "edited by" instead of being followed by "Ed." or "Eds."
+    pub edited_by: bool,
+    /// How editors are credited, with `{names}` standing for the list of
+    /// editors and `{ed}` for "Ed." or "Eds.", e.g. `"{names} ({ed})"` for
+    /// "J. Smith (Ed.)". Defaults to `"{names}, {ed}"`.
+    pub editor_template: String,
+    /// Whether the first author is inverted with their given name spelled
+    /// out while the other authors are abbreviated. This applies
+    /// [`InitialAbbreviation::AllButFirst`] to the authors only.
+    pub full_first_author: bool,
+    /// Which given names in lists of persons are abbreviated. IEEE
+    /// abbreviates all of them.
+    pub initial_abbreviation: InitialAbbreviation,
+    /// Whether periodical articles are cited in the compact form common for
+    /// electronic journals, e.g. "5(2), e0123456" instead of "vol. 5, no. 2,
+    /// Art. no. e0123456".
+    pub compact_periodicals: bool,
+    /// How DOIs are introduced.
+    pub doi_prefix: IeeeDoiPrefix,
+    /// The localized terms like month abbreviations and quotation marks. They
+    /// can be chosen for a language with [`Self::with_locale`].
+    pub locale: IeeeLocale,
+    /// Whether preprints are marked with a "\[Preprint\]" label ahead of
+    /// their URL.
+    pub preprint_label: bool,
+    /// Whether the affiliations of authors are printed in parentheses after
+    /// their names. They are omitted when the list is abbreviated with "et
+    /// al."
+    pub affiliations: bool,
+    /// How the initials of given names are printed.
+    pub initials: IeeeInitials,
+    /// Delimiters to wrap URLs in, e.g. angle brackets for "<https://…>".
+    pub url_delimiters: Option<(char, char)>,
+    /// Whether the editors of edited volumes are placed before the title of
+    /// the volume, e.g. "in J. Smith (Ed.), _Handbook_", instead of being
+    /// followed by "Ed." or "Eds." after it.
+    pub editors_before_container: bool,
+    /// Whether works with both authors and editors of their own, e.g. a
+    /// revised edition of a classic, lead with the editors. The authors then
+    /// follow the title, e.g. "J. Smith, Ed., _Emma_, by J. Austen".
+    pub lead_with_editors: bool,
+    /// Whether books are cited with their total number of pages, e.g.
+    /// "xii + 340 pp.", unless a page range is cited.
+    pub page_totals: bool,
+    /// Whether the locations of conferences are brought into the "City, ST,
+    /// Country" form by abbreviating US states and common country names.
+    pub normalize_locations: bool,
+    /// How the titles of the periodicals that articles appear in are cased.
+    pub periodical_casing: IeeeCasing,
+    /// How the titles of the books, anthologies, and reference works that
+    /// chapters and other parts appear in are cased.
+    pub book_casing: IeeeCasing,
+    /// Whether URLs, DOIs, and Latin-script names in references to entries
+    /// in right-to-left languages like Arabic or Hebrew are wrapped in
+    /// directional isolates so that the surrounding punctuation is not
+    /// reordered.
+    pub bidi_isolates: bool,
+    /// The roles that are credited in the author position of films and TV
+    /// shows, in order of priority. The persons of the first role that
+    /// anyone is affiliated with are used. TV episodes always credit their
+    /// directors and writers.
+    pub video_roles: Vec<PersonRole>,
+    /// How years are printed.
+    pub year_format: IeeeYearFormat,
+    /// How the period that ends a reference is set after a DOI.
+    pub doi_period: IeeeDoiPeriod,
+    /// Whether the edition of a multi-volume work precedes the volume, e.g.
+    /// "2nd ed., vol. 3" instead of "vol. 3, 2nd ed."
+    pub edition_before_volume: bool,
+    /// Whether Handles, ARKs, and arXiv ids are printed as resolver URLs,
+    /// e.g. "https://hdl.handle.net/1813/9174" instead of "hdl: 1813/9174".
+    pub identifier_urls: bool,
+    /// The separator between the initials and the rest of a name, e.g. a
+    /// non-breaking space to keep names on one line.
+    pub initials_separator: char,
+    /// The number of characters that commit hashes and checksums of
+    /// repositories are shortened to. `None` prints them in full.
+    pub hash_length: Option<usize>,
+    /// The phrase that introduces the conference at which a talk or poster
+    /// was presented, e.g. "Paper presented at".
+    pub presented_at: String,
+    /// Whether the arXiv id of an entry is printed after its DOI, e.g. for
+    /// journal articles that were also posted as preprints. Otherwise, it is
+    /// only printed for entries without a DOI.
+    pub arxiv_with_doi: bool,
+    /// Looks up the abbreviations of journal titles before the built-in
+    /// table is consulted.
+    pub journal_resolver: Option<IeeeJournalResolver>,
+    /// Whether references are annotated with how often they have been cited,
+    /// e.g. "(cited by 42)", for entries with a `cited-by` count.
+    pub cited_by_counts: bool,
+    /// Whether DOIs are followed by the agency that registered them, e.g.
+    /// "\[DataCite\]", for entries with a `registration-agency`.
+    pub doi_agencies: bool,
+    /// Whether references in a bibliography point to the entries listed in
+    /// their `see-also` field by number, e.g. "(see also [3], [7])". Entries
+    /// that have not been cited with a numeric style are left out.
+    pub see_also: bool,
+}
+
+/// How many untitled chapters and scenes are walked up at most to find the
+/// titled work they belong to.
+const MAX_PARENT_DEPTH: usize = 32;
+
+/// The codes of languages that are written from right to left.
+const RTL_LANGUAGES: [&str; 8] = ["ar", "dv", "fa", "he", "ps", "sd", "ur", "yi"];
+
+/// Unicode LEFT-TO-RIGHT ISOLATE.
+const LRI: char = '\u{2066}';
+
+/// Unicode POP DIRECTIONAL ISOLATE.
+const PDI: char = '\u{2069}';
+
+/// Configures how DOIs are printed in the IEEE style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeDoiPrefix {
+    /// Introduce the DOI with a label: "doi: 10.1109/5.771073".
+    Label,
+    /// Print the DOI as a resolver URL: "https://doi.org/10.1109/5.771073".
+    Url,
+    /// Print the bare DOI: "10.1109/5.771073".
+    Bare,
+}
+
+/// Configures how the titles of containers are cased in the IEEE style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeCasing {
+    /// Use the title case configuration: "Handbook of Robot Learning".
+    Title,
+    /// Use the sentence case configuration: "Handbook of robot learning".
+    Sentence,
+}
+
+/// Configures how years are printed in the IEEE style. Years before the
+/// common era are always marked with "BCE".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeYearFormat {
+    /// Print the full year: "2019", "500 BCE".
+    Full,
+    /// Print the last two digits of the year: "19", "500 BCE".
+    TwoDigit,
+    /// Mark all years with their era: "2019 CE", "500 BCE".
+    Era,
+}
+
+/// Configures the period at the end of references that end with a DOI in
+/// the IEEE style, which could otherwise be mistaken as part of the DOI.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeDoiPeriod {
+    /// Attach the period to the DOI: "doi: 10.1109/5.771073."
+    Attached,
+    /// Separate the period with a space: "doi: 10.1109/5.771073 ."
+    Spaced,
+    /// Omit the period: "doi: 10.1109/5.771073"
+    Omitted,
+}
+
+/// The group of trailing elements that an entry receives in the IEEE style,
+/// as determined by [`Ieee::classify`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IeeeAddonBranch {
+    /// Papers in conference proceedings and the proceedings themselves.
+    Proceedings,
+    /// Entries in reference works like encyclopedias.
+    Reference,
+    /// Software repositories.
+    Repository,
+    /// Films, TV shows, and online videos.
+    Video,
+    /// Patents.
+    Patent,
+    /// Journal articles marked as early access.
+    EarlyAccess,
+    /// Journal articles in the compact "12(3)" form.
+    CompactPeriodical,
+    /// Journal articles.
+    Periodical,
+    /// Technical reports.
+    Report,
+    /// Theses.
+    Thesis,
+    /// Legislation.
+    Legislation,
+    /// Unpublished manuscripts.
+    Manuscript,
+    /// Preprints on servers like arXiv.
+    Preprint,
+    /// Web pages and blog posts.
+    Web,
+    /// Entries published on a web page or blog.
+    WebParented,
+    /// Books and all other entries, including artworks, audio recordings,
+    /// legal cases, newspapers, and social media posts. These are cited with
+    /// their editors, volume, edition, publisher, date, and location within
+    /// their container, whichever of them are present.
+    Generic,
+}
+
+/// Looks up the abbreviation of a journal title in the IEEE style, e.g. in a
+/// database or file that is too large to be built in.
+#[derive(Clone)]
+pub struct IeeeJournalResolver(Arc<ResolveFn>);
+
+type ResolveFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+impl IeeeJournalResolver {
+    /// Creates a resolver from a function that returns the abbreviation of a
+    /// title if it knows one. Other titles are abbreviated with the built-in
+    /// table.
+    pub fn new(resolve: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(resolve))
+    }
+}
+
+impl fmt::Debug for IeeeJournalResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IeeeJournalResolver(..)")
+    }
+}
+
+/// Resolvers are only equal if they share the same function.
+impl PartialEq for IeeeJournalResolver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for IeeeJournalResolver {}
+
+/// Configures how initials are printed in the IEEE style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeInitials {
+    /// Initials with periods separated by spaces: "J. A. Smith".
+    Spaced,
+    /// Initials with periods run together: "J.A. Smith".
+    Unspaced,
+    /// Initials without periods run together: "JA Smith".
+    NoPeriods,
+}
+
+/// The label that follows the persons credited for a film or TV show.
+fn video_role_label(role: &PersonRole) -> Option<&str> {
+    Some(match role {
+        PersonRole::Director => "Director",
+        PersonRole::ExecutiveProducer => "Executive Prod",
+        PersonRole::Producer => "Producer",
+        PersonRole::Writer => "Writer",
+        PersonRole::Presenter => "Presenter",
+        PersonRole::Narrator => "Narrator",
+        PersonRole::Unknown(role) => role,
+        _ => return None,
+    })
+}
+
+/// Whether a formatted name is written in the Latin script.
+fn is_latin(name: &str) -> bool {
+    let mut letters = name.chars().filter(|c| c.is_alphabetic()).peekable();
+    letters.peek().is_some() && letters.all(|c| c < '\u{0250}')
+}
+
+/// The annotation for the languages of an entry or its canonical parent,
+/// e.g. "in German" or "in English and French". Languages with a configured
+/// annotation use it, the others are named in English and grouped. The
+/// annotations of multilingual works are joined with the locale's
+/// conjunction. Unknown language codes are skipped.
+fn language_annotation(
+    locale: &IeeeLocale,
+    entry: &Entry,
+    canonical: &Entry,
+    events: &mut Vec<FormatEvent>,
+) -> Option<String> {
+    let langs: Vec<&LanguageIdentifier> = entry
+        .languages()
+        .or_else(|| canonical.languages())
+        .filter(|langs| !langs.is_empty())
+        .map(|langs| langs.iter().collect())
+        .or_else(|| entry.language().or_else(|| canonical.language()).map(|l| vec![l]))?;
+
+    let conjunction = &locale.language_conjunction;
+    let mut annotations = vec![];
+    let mut names = vec![];
+    for lang in langs {
+        let code = lang.language.as_str();
+        if let Some(annotation) = locale.language_annotations.get(code) {
+            if !names.is_empty() {
+                annotations.push(format!("in {}", join_list(&names, conjunction)));
+                names.clear();
+            }
+            annotations.push(annotation.clone());
+        } else if let Some(language) = Language::from_639_1(code) {
+            names.push(language.to_name().to_string());
+        } else {
+            // The title and the addons may both ask for the annotation.
+            let event = FormatEvent::SkippedLanguage(lang.to_string());
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+    }
+
+    if !names.is_empty() {
+        annotations.push(format!("in {}", join_list(&names, conjunction)));
+    }
+
+    if annotations.is_empty() {
+        None
+    } else {
+        Some(join_list(&annotations, conjunction))
+    }
+}
+
+/// Joins items with a conjunction and, for three or more items, a serial
+/// comma: "A and B" or "A, B, and C".
+fn join_list(items: &[String], conjunction: &str) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [first, second] => format!("{} {} {}", first, conjunction, second),
+        [init @ .., last] => format!("{}, {} {}", init.join(", "), conjunction, last),
+    }
+}
+
+/// Formats the id of an article in the compact periodical form. Electronic
+/// locators like "e0123456" are printed as-is, other ids are introduced with
+/// "art."
+fn format_article_id(id: &str) -> String {
+    let mut chars = id.chars();
+    let e_locator = matches!(chars.next(), Some('e') | Some('E'))
+        && !chars.as_str().is_empty()
+        && chars.all(|c| c.is_ascii_digit());
+
+    if e_locator {
+        id.to_string()
+    } else {
+        format!("art. {}", id)
+    }
+}
+
+/// Configures when to print access dates in the IEEE style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IeeeAccessDateVisibility {
+    /// Never print access dates.
+    Never,
+    /// Print access dates unless the URL is on one of the
+    /// [`persistent_hosts`](Ieee::persistent_hosts).
+    NonPersistent,
+    /// Always print access dates.
+    Always,
 }
 
 fn get_canonical_parent(entry: &Entry) -> Option<&Entry> {
@@ -55,12 +443,61 @@ fn get_canonical_parent(entry: &Entry) -> Option<&Entry> {
     let entry_spec = select!(Entry > ("p":(Reference | Repository)));
     let proceedings = select!(* > ("p":(Conference | Proceedings | Periodical)));
 
-    section
-        .apply(entry)
-        .or_else(|| proceedings.apply(entry))
-        .or_else(|| anthology.apply(entry))
-        .or_else(|| entry_spec.apply(entry))
-        .and_then(|mut bindings| bindings.remove("p"))
+    bound_parent(&section, entry)
+        .or_else(|| bound_parent(&proceedings, entry))
+        .or_else(|| bound_parent(&anthology, entry))
+        .or_else(|| bound_parent(&entry_spec, entry))
+}
+
+/// Where a reference points to within its container.
+struct PartContext<'a> {
+    /// A larger division of the work like "Part II".
+    part: Option<&'a str>,
+    chapter: Option<u32>,
+    section: Option<u32>,
+    /// The disambiguation index of the record, appended to its year.
+    disamb: Option<usize>,
+}
+
+/// Whether the serial number of an untitled chapter names a larger division
+/// of a work like "Part II" or "Book III" rather than a chapter number.
+fn is_part(serial_number: &str) -> bool {
+    let word = serial_number.split_whitespace().next().unwrap_or_default();
+    word.eq_ignore_ascii_case("part") || word.eq_ignore_ascii_case("book")
+}
+
+/// Whether an entry is a numbered episode of a TV series.
+fn is_tv_episode(entry: &Entry) -> bool {
+    select!((Video["issue", "volume"]) > Video).matches(entry)
+}
+
+/// Formats the season and episode of a TV episode like "S1E3".
+///
+/// Episodes that span multiple seasons or have a non-numeric episode are
+/// numbered by the usual addons instead.
+fn episode_locator(entry: &Entry) -> Option<String> {
+    if !is_tv_episode(entry) {
+        return None;
+    }
+
+    let season = entry.volume()?;
+    match entry.issue()? {
+        NumOrStr::Number(episode) if season.start == season.end => {
+            Some(format!("S{}E{}", season.start, episode))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the repository a preprint was posted to.
+fn preprint_parent(entry: &Entry) -> Option<&Entry> {
+    bound_parent(&select!((Article | Book | Anthos) > ("p": Repository)), entry)
+}
+
+/// Applies the selector and returns the entry bound to `p`. Selectors that
+/// match without binding `p` yield `None`.
+fn bound_parent<'a>(selector: &Selector, entry: &'a Entry) -> Option<&'a Entry> {
+    selector.apply(entry).and_then(|mut bindings| bindings.remove("p"))
 }
 
 impl Default for Ieee {
@@ -73,29 +510,200 @@ impl Default for Ieee {
             sentence_case: SentenceCase::default(),
             title_case,
             et_al_threshold: Some(6),
+            et_al_names: 2,
+            editor_et_al_threshold: Some(6),
             abbreviate_journals: true,
+            show_original_date: false,
+            persistent_hosts: ["doi.org", "hdl.handle.net", "purl.org", "w3id.org"]
+                .iter()
+                .map(|&h| h.to_string())
+                .collect(),
+            access_dates: IeeeAccessDateVisibility::NonPersistent,
+            capitalize_in: false,
+            max_title_chars: None,
+            italic_et_al: false,
+            italics: true,
+            edited_by: false,
+            editor_template: "{names}, {ed}".to_string(),
+            full_first_author: false,
+            initial_abbreviation: InitialAbbreviation::Always,
+            compact_periodicals: false,
+            doi_prefix: IeeeDoiPrefix::Label,
+            locale: IeeeLocale::default(),
+            preprint_label: false,
+            affiliations: false,
+            initials: IeeeInitials::Spaced,
+            url_delimiters: None,
+            editors_before_container: false,
+            lead_with_editors: false,
+            page_totals: false,
+            normalize_locations: false,
+            periodical_casing: IeeeCasing::Title,
+            book_casing: IeeeCasing::Title,
+            bidi_isolates: false,
+            video_roles: vec![PersonRole::Director, PersonRole::ExecutiveProducer],
+            year_format: IeeeYearFormat::Full,
+            doi_period: IeeeDoiPeriod::Attached,
+            edition_before_volume: false,
+            identifier_urls: false,
+            initials_separator: ' ',
+            hash_length: Some(7),
+            presented_at: "Presented at".to_string(),
+            arxiv_with_doi: false,
+            journal_resolver: None,
+            cited_by_counts: false,
+            doi_agencies: false,
+            see_also: false,
         }
     }
 }
 
 impl Ieee {
     /// Creates a new IEEE bibliography generator.
+    ///
+    /// Author lists with six or more names are shortened to the first two
+    /// names followed by "et al."
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a new IEEE bibliography generator that follows the 2018 IEEE
+    /// Reference Guide strictly.
+    ///
+    /// Unlike [`Self::new`], author and editor lists are only shortened once
+    /// they have seven or more names and then only show the first name
+    /// followed by "et al." (e.g. "J. K. Author et al.").
+    pub fn strict_2018() -> Self {
+        Self {
+            et_al_threshold: Some(7),
+            et_al_names: 1,
+            editor_et_al_threshold: Some(7),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a copy of this generator that uses the
+    /// [built-in terms](IeeeLocale::from_language) of another language, e.g.
+    /// to render the same bibliography in German. The month abbreviations,
+    /// quotation marks, and language annotations are replaced together, all
+    /// other options are kept.
+    pub fn with_locale(&self, language: &LanguageIdentifier) -> Self {
+        Self {
+            locale: IeeeLocale::from_language(language),
+            ..self.clone()
+        }
+    }
+
+    /// Formats persons with their initials first (e.g. "J. A. Smith").
+    /// Organizations are followed by their acronym, e.g. "National Institutes
+    /// of Health (NIH)".
+    fn name_list(&self, persons: &[Person]) -> Vec<String> {
+        self.name_list_with(persons, self.initial_abbreviation)
+    }
+
+    /// Formats persons like [`Self::name_list`], but with the given
+    /// abbreviation of their given names.
+    fn name_list_with(
+        &self,
+        persons: &[Person],
+        abbreviation: InitialAbbreviation,
+    ) -> Vec<String> {
+        let mut names = name_list_straight(persons, abbreviation);
+
+        if self.initials != IeeeInitials::Spaced || self.initials_separator != ' ' {
+            let delimiter =
+                if self.initials == IeeeInitials::NoPeriods { None } else { Some(".") };
+            for (i, (name, person)) in names.iter_mut().zip(persons).enumerate() {
+                if !abbreviation.abbreviates(i) {
+                    continue;
+                }
+
+                let mut initials = person.initials(delimiter);
+                if self.initials != IeeeInitials::Spaced {
+                    initials = initials.map(|i| i.split_whitespace().collect());
+                }
+
+                let mut rest = person.clone();
+                rest.given_name = None;
+                let rest = rest.given_first(false);
+                *name = match initials {
+                    Some(initials) => {
+                        format!("{}{}{}", initials, self.initials_separator, rest)
+                    }
+                    None => rest,
+                };
+            }
+        }
+
+        for (name, person) in names.iter_mut().zip(persons) {
+            if let (None, Some(acronym)) = (&person.given_name, &person.acronym) {
+                *name += &format!(" ({})", acronym);
+            }
+        }
+
+        names
+    }
+
+    /// Whether a list with `count` names is abbreviated with "et al."
+    fn is_et_al(&self, count: usize) -> bool {
+        let threshold = self.et_al_threshold.unwrap_or(0);
+        threshold > 0 && count as u32 >= threshold
+    }
+
+    /// Joins the names of editors, abbreviated with "et al." according to
+    /// the [`editor_et_al_threshold`](Self::editor_et_al_threshold).
+    fn editor_list(&self, editors: &[Person]) -> DisplayString {
+        let threshold = self.editor_et_al_threshold.unwrap_or(0);
+        let et_al = threshold > 0 && editors.len() as u32 >= threshold;
+        self.join_names(self.name_list(editors), et_al)
+    }
+
+    /// Formats a list of editors followed by "Ed." or "Eds." according to
+    /// the [`editor_template`](Self::editor_template).
+    fn editor_credit(&self, editors: &[Person]) -> DisplayString {
+        let ed = if editors.len() > 1 { "Eds." } else { "Ed." };
+        let mut res = DisplayString::new();
+        for (i, piece) in self.editor_template.split("{names}").enumerate() {
+            if i > 0 {
+                res += self.editor_list(editors);
+            }
+            res += &piece.replace("{ed}", ed);
+        }
+        res
+    }
+
     fn and_list(&self, names: Vec<String>) -> String {
+        self.and_list_display(names).value
+    }
+
+    fn and_list_display(&self, names: Vec<String>) -> DisplayString {
+        let et_al = self.is_et_al(names.len());
+        self.join_names(names, et_al)
+    }
+
+    /// Joins names into a list that is shortened with "et al." if `et_al` is
+    /// set.
+    fn join_names(&self, names: Vec<String>, et_al: bool) -> DisplayString {
         let name_len = names.len() as u32;
-        let mut res = String::new();
-        let threshold = self.et_al_threshold.unwrap_or(0);
+        let mut res = DisplayString::new();
 
         for (index, name) in names.into_iter().enumerate() {
-            if threshold > 0 && index > 1 && name_len >= threshold {
+            if et_al && index as u32 >= self.et_al_names.max(1) {
                 break;
             }
 
             res += &name;
 
+            if et_al {
+                res += if self.et_al_names <= 1 { " " } else { ", " };
+                continue;
+            }
+
+            if name_len == 2 && index == 0 {
+                res += " and ";
+                continue;
+            }
+
             if (index as i32) <= name_len as i32 - 2 {
                 res += ", ";
             }
@@ -104,23 +712,352 @@ impl Ieee {
             }
         }
 
-        if threshold > 0 && name_len >= threshold {
-            res += "et al."
+        if et_al {
+            if self.italic_et_al {
+                self.start_italic(&mut res);
+            }
+            res += "et al.";
+            res.commit_formats();
+        }
+
+        res
+    }
+
+    /// Appends the original publication date (e.g. "(orig. pub. 1924)") to a
+    /// formatted date if it is enabled and present.
+    fn with_original_date(
+        &self,
+        entry: &Entry,
+        canonical: &Entry,
+        date: String,
+    ) -> String {
+        if !self.show_original_date {
+            return date;
+        }
+
+        match entry.original_date().or_else(|| canonical.original_date()) {
+            Some(original) => {
+                format!("{} (orig. pub. {})", date, self.format_year(original))
+            }
+            None => date,
+        }
+    }
+
+    /// Returns the visit date of a URL if it should be printed.
+    fn visit_date<'u>(&self, url: &'u QualifiedUrl) -> Option<&'u Date> {
+        let host = url.value.host_str().unwrap_or_default();
+        let persistent = self
+            .persistent_hosts
+            .iter()
+            .any(|p| host == p || host.ends_with(&format!(".{}", p)));
+
+        match self.access_dates {
+            IeeeAccessDateVisibility::Never => None,
+            IeeeAccessDateVisibility::NonPersistent if persistent => None,
+            _ => url.visit_date.as_ref(),
+        }
+    }
+
+    /// Formats a commit hash ("commit 3f9c2a1") or a checksum prefixed with
+    /// its algorithm ("SHA-256: 9f86d08…"), shortened to the hash length.
+    fn format_commit(&self, commit: &str) -> String {
+        let (label, hash) = match commit.split_once(':') {
+            Some((algorithm, hash)) => {
+                let mut label = algorithm.trim().to_uppercase();
+                if label.starts_with("SHA")
+                    && label[3..].starts_with(|c: char| c.is_ascii_digit())
+                {
+                    label.insert(3, '-');
+                }
+                (label + ": ", hash.trim())
+            }
+            None => ("commit ".to_string(), commit),
+        };
+
+        match self.hash_length {
+            Some(len) if hash.chars().count() > len => {
+                let short: String = hash.chars().take(len).collect();
+                if label.starts_with("commit") {
+                    label + &short
+                } else {
+                    format!("{}{}…", label, short)
+                }
+            }
+            _ => label + hash,
+        }
+    }
+
+    /// Starts an italic span unless [`italics`](Self::italics) are disabled.
+    fn start_italic(&self, res: &mut DisplayString) {
+        if self.italics {
+            res.start_format(Formatting::Italic);
+        }
+    }
+
+    /// Abbreviates a journal title, preferring the abbreviation of the
+    /// [`journal_resolver`](Self::journal_resolver).
+    fn abbreviate_journal(&self, title: &str) -> String {
+        self.journal_resolver
+            .as_ref()
+            .and_then(|resolver| (resolver.0)(title))
+            .unwrap_or_else(|| abbreviations::abbreviate_journal(title))
+    }
+
+    /// Abbreviates a zero-indexed month, preferring the custom abbreviations.
+    fn month_abbr(&self, month: u8) -> String {
+        en::get_month_abbr(month, true, self.locale.month_abbreviations.as_ref()).unwrap()
+    }
+
+    /// The editors of a work that also has authors if they are to lead its
+    /// reference instead of the authors.
+    fn lead_editors<'a>(
+        &self,
+        entry: &'a Entry,
+        canonical: &Entry,
+    ) -> Option<&'a [Person]> {
+        if !self.lead_with_editors
+            || entry.authors().unwrap_or_default().is_empty()
+            || self.addon_branch(entry, canonical) != IeeeAddonBranch::Generic
+        {
+            return None;
+        }
+
+        entry.editors().filter(|eds| !eds.is_empty())
+    }
+
+    /// The editors of the edited volume an entry is part of if they are to be
+    /// placed before the title of the volume.
+    fn leading_editors<'a>(
+        &self,
+        entry: &Entry,
+        canonical: &'a Entry,
+    ) -> Option<&'a [Person]> {
+        if !self.editors_before_container
+            || entry == canonical
+            || entry.editors().is_some()
+            || !select!(Book | Anthology).matches(canonical)
+        {
+            return None;
+        }
+
+        canonical.editors().filter(|eds| !eds.is_empty())
+    }
+
+    /// Formats the date of an entry along with its original date. A
+    /// publication status like "under review" takes the place of the date.
+    fn date_element(
+        &self,
+        entry: &Entry,
+        canonical: &Entry,
+        disamb: Option<usize>,
+    ) -> Option<String> {
+        if let Some(status) = entry.status() {
+            return Some(status.to_string());
+        }
+
+        resolve_date(entry, canonical).map(|date| {
+            self.with_original_date(entry, canonical, self.format_date(date, disamb))
+        })
+    }
+
+    /// Formats the year of a date.
+    fn format_year(&self, date: &Date) -> String {
+        match self.year_format {
+            IeeeYearFormat::TwoDigit if date.year > 0 => {
+                format!("{:02}", date.year % 100)
+            }
+            IeeeYearFormat::Era => date.display_year_opt(true, false, true, false),
+            _ => date.display_year(),
+        }
+    }
+
+    /// Formats a date with abbreviated month names.
+    fn format_date(&self, date: &Date, disamb: Option<usize>) -> String {
+        let mut res = String::new();
+        if let Some(month) = date.month {
+            res += &(if let Some(day) = date.day {
+                format!("{} {},", self.month_abbr(month), day + 1)
+            } else {
+                self.month_abbr(month)
+            });
+            res += " ";
+        }
+
+        res += &self.format_year(date);
+        if let Some(disamb) = disamb {
+            res.push(alph_designator(disamb).to_ascii_uppercase());
+        }
+        res
+    }
+
+    /// Appends a linked URL, wrapped in the configured delimiters and, if
+    /// `isolate` is set, in directional isolates.
+    fn push_url(&self, res: &mut DisplayString, url: &QualifiedUrl, isolate: bool) {
+        if isolate {
+            res.push(LRI);
+        }
+
+        if let Some((open, _)) = self.url_delimiters {
+            res.push(open);
+        }
+
+        res.start_format(Formatting::Link(url.value.to_string()));
+        *res += url.value.as_str();
+        res.commit_formats();
+
+        if let Some((_, close)) = self.url_delimiters {
+            res.push(close);
+        }
+
+        if isolate {
+            res.push(PDI);
+        }
+    }
+
+    /// Whether left-to-right runs in the reference of an entry in a
+    /// right-to-left language are wrapped in directional isolates.
+    fn isolates_ltr(&self, entry: &Entry, canonical: &Entry) -> bool {
+        let lang = entry.language().or_else(|| canonical.language());
+        self.bidi_isolates
+            && matches!(lang, Some(lang) if RTL_LANGUAGES.contains(&lang.language.as_str()))
+    }
+
+    /// Wraps a left-to-right run in directional isolates if the entry is in
+    /// a right-to-left language.
+    fn isolate_ltr(&self, entry: &Entry, canonical: &Entry, run: String) -> String {
+        if self.isolates_ltr(entry, canonical) {
+            format!("{}{}{}", LRI, run, PDI)
+        } else {
+            run
+        }
+    }
+
+    /// Formats a DOI with the configured prefix.
+    fn format_doi(&self, entry: &Entry, doi: &str) -> String {
+        let mut res = match self.doi_prefix {
+            IeeeDoiPrefix::Label => format!("doi: {}", doi),
+            IeeeDoiPrefix::Url => format!("https://doi.org/{}", doi),
+            IeeeDoiPrefix::Bare => doi.to_string(),
+        };
+
+        if let Some(agency) = entry.registration_agency().filter(|_| self.doi_agencies) {
+            res += &format!(" [{}]", agency);
+        }
+
+        res
+    }
+
+    /// Formats the persistent identifiers of an entry other than its DOI.
+    /// Handles, ARKs, and arXiv ids are printed as resolver URLs if
+    /// [`identifier_urls`](Self::identifier_urls) is set.
+    fn format_identifiers(&self, entry: &Entry) -> Vec<String> {
+        // Removes a scheme like "hdl:" the identifier might start with.
+        let strip = |id: &str, scheme: &str| {
+            let id = match id.get(..scheme.len()) {
+                Some(start) if start.eq_ignore_ascii_case(scheme) => &id[scheme.len()..],
+                _ => id,
+            };
+            id.trim_start_matches(&[':', '/'][..]).to_string()
+        };
+
+        let mut res = vec![];
+        if let Some(handle) = entry.handle().map(|h| strip(h, "hdl")) {
+            res.push(if self.identifier_urls {
+                format!("https://hdl.handle.net/{}", handle)
+            } else {
+                format!("hdl: {}", handle)
+            });
+        }
+
+        if let Some(ark) = entry.ark().map(|a| strip(a, "ark")) {
+            res.push(if self.identifier_urls {
+                format!("https://n2t.net/ark:/{}", ark)
+            } else {
+                format!("ark: {}", ark)
+            });
+        }
+
+        if let Some(urn) = entry.urn().map(|u| strip(u, "urn")) {
+            res.push(format!("urn: {}", urn));
         }
 
+        let arxiv = entry
+            .arxiv()
+            .filter(|_| self.arxiv_with_doi || entry.doi().is_none())
+            .map(|a| strip(a, "arxiv"));
+        if let Some(arxiv) = arxiv {
+            res.push(if self.identifier_urls {
+                format!("https://arxiv.org/abs/{}", arxiv)
+            } else {
+                format!("arXiv: {}", arxiv)
+            });
+        }
+
+        res
+    }
+
+    /// Formats the volume number followed by the title of the cited volume,
+    /// if there is one (e.g. "vol. 3: Late Essays").
+    fn format_volume(
+        &self,
+        entry: &Entry,
+        canonical: &Entry,
+        vols: &std::ops::Range<i64>,
+    ) -> String {
+        let mut res = format_range("vol.", "vols.", vols);
+        if let Some(title) = entry.volume_title().or_else(|| canonical.volume_title()) {
+            res += ": ";
+            res += &title.format_title_case(&self.title_case);
+        }
         res
     }
 
+    /// Formats the volume and edition of an entry in the configured order.
+    fn volume_and_edition(&self, entry: &Entry, canonical: &Entry) -> Vec<String> {
+        let volume = entry
+            .volume()
+            .or_else(|| canonical.volume())
+            .map(|vols| self.format_volume(entry, canonical, vols));
+        let edition = canonical.edition().and_then(format_edition);
+
+        let (first, second) = if self.edition_before_volume {
+            (edition, volume)
+        } else {
+            (volume, edition)
+        };
+        first.into_iter().chain(second).collect()
+    }
+
+    /// Truncates a formatted title to `max_title_chars` if it is longer.
+    fn truncate_title(&self, title: String) -> String {
+        let max = match self.max_title_chars {
+            Some(max) if title.chars().count() > max => max,
+            _ => return title,
+        };
+
+        let head: String = title.chars().take(max + 1).collect();
+        let end = head.rfind(char::is_whitespace).unwrap_or_else(|| {
+            head.char_indices().nth(max).map_or(head.len(), |(i, _)| i)
+        });
+
+        let cut = head[..end]
+            .trim_end_matches(|c: char| c.is_whitespace() || c.is_ascii_punctuation());
+        format!("{}…", cut)
+    }
+
     fn show_url(&self, entry: &Entry) -> bool {
-        entry.url_any().is_some()
+        entry.url_any().is_some() || entry.archive_url().is_some()
     }
 
-    fn get_author(&self, entry: &Entry, canonical: &Entry) -> (String, Vec<Person>) {
+    fn get_author(
+        &self,
+        entry: &Entry,
+        canonical: &Entry,
+    ) -> (DisplayString, Vec<Person>) {
         #[derive(Clone, Debug)]
         enum AuthorRole {
             Normal,
-            Director,
-            ExecutiveProducer,
+            Video(PersonRole),
         }
 
         impl Default for AuthorRole {
@@ -133,18 +1070,19 @@ impl Ieee {
         let mut role = AuthorRole::default();
         let mut pers_refs = vec![];
         if entry.entry_type == Video {
-            let tv_series = select!((Video["issue", "volume"]) > Video);
             let dirs = entry.affiliated_with_role(PersonRole::Director);
 
-            if tv_series.matches(entry) {
+            if is_tv_episode(entry) {
                 // TV episode
-                let mut dir_name_list_straight = name_list_straight(&dirs)
+                let mut dir_name_list_straight = self
+                    .name_list(&dirs)
                     .into_iter()
                     .map(|s| format!("{} (Director)", s))
                     .collect::<Vec<String>>();
 
                 let writers = entry.affiliated_with_role(PersonRole::Writer);
-                let mut writers_name_list_straight = name_list_straight(&writers)
+                let mut writers_name_list_straight = self
+                    .name_list(&writers)
                     .into_iter()
                     .map(|s| format!("{} (Writer)", s))
                     .collect::<Vec<String>>();
@@ -156,28 +1094,52 @@ impl Ieee {
                     pers_refs.extend(writers);
                 }
             } else {
-                // Film
-                if !dirs.is_empty() {
-                    names = Some(name_list_straight(&dirs));
-                    pers_refs.extend(dirs);
-                    role = AuthorRole::Director;
-                } else {
-                    // TV show
-                    let prods = entry.affiliated_with_role(PersonRole::ExecutiveProducer);
-
-                    if !prods.is_empty() {
-                        names = Some(name_list_straight(&prods));
-                        pers_refs.extend(prods);
-                        role = AuthorRole::ExecutiveProducer;
+                // Film or TV show
+                for video_role in &self.video_roles {
+                    let persons = entry.affiliated_with_role(video_role.clone());
+                    if !persons.is_empty() {
+                        names = Some(self.name_list(&persons));
+                        pers_refs.extend(persons);
+                        role = AuthorRole::Video(video_role.clone());
+                        break;
                     }
                 }
             }
         }
 
+        // Some entries list all of their authors regardless of the threshold.
+        let mut authors_et_al = false;
         let authors = if let Some(names) = names {
+            authors_et_al =
+                self.is_et_al(names.len()) && entry.all_authors() != Some(&true);
             Some(names)
-        } else if let Some(authors) = entry.authors().or_else(|| canonical.authors()) {
-            let list = name_list_straight(&authors);
+        } else if self.lead_editors(entry, canonical).is_some() {
+            None
+        } else if let Some(authors) = entry.authors().or_else(|| {
+            // The authors of an edited container did not write the entry.
+            if canonical.editors().is_none() {
+                canonical.authors()
+            } else {
+                None
+            }
+        }) {
+            let mut list = if self.full_first_author {
+                self.name_list_with(authors, InitialAbbreviation::AllButFirst)
+            } else {
+                self.name_list(authors)
+            };
+            authors_et_al =
+                self.is_et_al(authors.len()) && entry.all_authors() != Some(&true);
+            if self.affiliations && !authors_et_al {
+                for (name, author) in list.iter_mut().zip(authors) {
+                    if let Some(affiliation) = &author.affiliation {
+                        *name += &format!(" ({})", affiliation);
+                    }
+                }
+            }
+            for name in list.iter_mut().filter(|name| is_latin(name)) {
+                *name = self.isolate_ltr(entry, canonical, std::mem::take(name));
+            }
             pers_refs.extend(authors.iter().cloned());
             Some(list)
         } else {
@@ -186,40 +1148,75 @@ impl Ieee {
 
         let al = if let Some(authors) = authors {
             let count = authors.len();
-            let amps = self.and_list(authors);
+            let mut amps = self.join_names(authors, authors_et_al);
             match role {
-                AuthorRole::Normal => amps,
-                AuthorRole::ExecutiveProducer if count == 1 => {
-                    format!("{}, Executive Prod", amps)
+                AuthorRole::Normal => {
+                    let contributors =
+                        entry.affiliated_with_role(PersonRole::Contributor);
+                    if !contributors.is_empty() {
+                        amps += ", with ";
+                        amps += self.and_list_display(self.name_list(&contributors));
+                        pers_refs.extend(contributors);
+                    }
+                }
+                AuthorRole::Video(role) => {
+                    if let Some(label) = video_role_label(&role) {
+                        amps += ", ";
+                        amps += label;
+                        if count > 1 {
+                            amps.push('s');
+                        }
+                    }
                 }
-                AuthorRole::ExecutiveProducer => format!("{}, Executive Prods", amps),
-                AuthorRole::Director if count == 1 => format!("{}, Director", amps),
-                AuthorRole::Director => format!("{}, Directors", amps),
             }
+            amps
         } else if let Some(eds) = entry.editors() {
-            let res = if !eds.is_empty() {
-                format!(
-                    "{}, {}",
-                    self.and_list(name_list_straight(&eds)),
-                    if eds.len() == 1 { "Ed." } else { "Eds." }
-                )
-            } else {
-                String::new()
-            };
+            let mut res = DisplayString::new();
+            if !eds.is_empty() {
+                res += self.editor_credit(eds);
+            }
             pers_refs.extend(eds.iter().cloned());
             res
         } else {
-            String::new()
+            let comps = entry.affiliated_with_role(PersonRole::Compiler);
+            // Translators of videos are credited with the subtitles instead.
+            let trans = if entry.entry_type != Video {
+                entry.affiliated_with_role(PersonRole::Translator)
+            } else {
+                vec![]
+            };
+            let mut res = DisplayString::new();
+            if !comps.is_empty() {
+                res += self.and_list_display(self.name_list(&comps));
+                res += if comps.len() == 1 { ", Comp." } else { ", Comps." };
+                pers_refs.extend(comps);
+            } else if !trans.is_empty() {
+                res += self.and_list_display(self.name_list(&trans));
+                res += ", Trans.";
+                pers_refs.extend(trans);
+            } else if entry.anonymous() == Some(&true) {
+                res += "Anon.";
+            }
+            res
         };
 
         (al, pers_refs)
     }
 
-    fn get_title_element(&self, entry: &Entry, canonical: &Entry) -> DisplayString {
+    fn get_title_element(
+        &self,
+        entry: &Entry,
+        canonical: &Entry,
+        initial: bool,
+        events: &mut Vec<FormatEvent>,
+    ) -> DisplayString {
         // Article > Periodical: "<SC>," _<abbr(TC)>_
-        // Any > Conference:     <SC>. Presented at <abbr(TC)>
-        // Any > Anthology:      "<SC>," in _<TC>_ (TC, no. <issue>)
+        // Any > Conference:     <SC>. <presented at> <abbr(TC)>
+        // Any > Anthology:      "<SC>," in _<TC>_ (TC, no. <issue> | vol. <volume>)
+        // Numbered in a collection: "<SC>," no. <issue> in _<TC>_
+        // Translated container: "<SC>," in _<TC>_ [<translated TC>]
         // entry != canonical:   "<SC>," in _<TC>_
+        // Same title as container: _<TC>_
         // Legislation:          _<serial number>, <TC>_
         // Repository, Video, Reference, Book, Proceedings, Anthology, : _<TC>_
         // Fallback:             "<SC>,"
@@ -229,15 +1226,26 @@ impl Ieee {
         if entry != canonical {
             let canon_title = canonical.title();
 
-            if let Some(title) = entry.title() {
-                let sentence = title.canonical.format_sentence_case(&self.sentence_case);
+            // Entries that repeat the title of their container, e.g. because
+            // of duplicated data, are only cited with the container title.
+            let duplicate = matches!(
+                (entry.title(), canon_title),
+                (Some(a), Some(b)) if a.canonical.value.trim().to_lowercase()
+                    == b.canonical.value.trim().to_lowercase()
+            );
+
+            if let Some(title) = entry.title().filter(|_| !duplicate) {
+                let sentence = self.truncate_title(
+                    title.canonical.format_sentence_case(&self.sentence_case),
+                );
                 if canonical.entry_type == Conference {
                     res += &sentence;
                     res.push('.');
                 } else {
-                    res += "“";
+                    res += &self.locale.quotes.0;
                     res += &sentence;
-                    res += ",”";
+                    res.push(',');
+                    res += &self.locale.quotes.1;
                 }
 
                 if canon_title.is_some() {
@@ -246,38 +1254,75 @@ impl Ieee {
             }
 
             if let Some(title) = canon_title {
-                let title_case = title.canonical.format_title_case(&self.title_case);
+                let casing = match canonical.entry_type {
+                    Periodical => self.periodical_casing,
+                    Book | Anthology | Reference => self.book_casing,
+                    _ => IeeeCasing::Title,
+                };
+                let cased = match casing {
+                    IeeeCasing::Title => {
+                        title.canonical.format_title_case(&self.title_case)
+                    }
+                    IeeeCasing::Sentence => {
+                        title.canonical.format_sentence_case(&self.sentence_case)
+                    }
+                };
                 let ct = if self.abbreviate_journals {
-                    abbreviations::abbreviate_journal(&title_case)
+                    self.abbreviate_journal(&cased)
                 } else {
-                    title_case.to_string()
+                    cased
                 };
 
                 if canonical.entry_type == Conference {
-                    res += "Presented at ";
+                    let presented_at = self.presented_at.trim();
+                    if !presented_at.is_empty() {
+                        res += presented_at;
+                        res.push(' ');
+                    }
                     res += &ct;
                 } else {
-                    if let Some(lang) = entry.language().or_else(|| canonical.language())
+                    if let Some(lang) =
+                        language_annotation(&self.locale, entry, canonical, events)
                     {
-                        res += "(in ";
-                        res += Language::from_639_1(lang.language.as_str())
-                            .unwrap()
-                            .to_name();
-                        res += ") ";
+                        res += &format!("({}) ", lang);
                     }
 
-                    if entry.entry_type != Article || canonical.entry_type != Periodical {
-                        res += "in ";
+                    // Works numbered within a collected edition.
+                    let number = entry
+                        .issue()
+                        .filter(|_| matches!(canonical.entry_type, Anthology | Book));
+                    if duplicate {
+                        // There is no entry title to connect to the container.
+                    } else if let Some(number) = number {
+                        res += if initial && res.is_empty() { "No. " } else { "no. " };
+                        res += &format!("{} in ", number);
+                    } else if entry.entry_type != Article
+                        || canonical.entry_type != Periodical
+                    {
+                        res += if self.capitalize_in || (initial && res.is_empty()) {
+                            "In "
+                        } else {
+                            "in "
+                        };
+                    }
+
+                    if let Some(eds) = self.leading_editors(entry, canonical) {
+                        res += self.editor_list(eds);
+                        res += if eds.len() > 1 { " (Eds.), " } else { " (Ed.), " };
                     }
-                    res.start_format(Formatting::Italic);
+                    self.start_italic(&mut res);
                     res += &ct;
                     res.commit_formats();
 
+                    if let Some(translated) = &title.translated {
+                        res += " [";
+                        res += &translated.format_title_case(&self.title_case);
+                        res += "]";
+                    }
+
                     // Do the series parentheses thing here
                     let spec = select!(Anthology > ("p":(Anthology["title"])));
-                    if let Some(mut bindings) = spec.apply(canonical) {
-                        let parenth_anth = bindings.remove("p").unwrap();
-
+                    if let Some(parenth_anth) = bound_parent(&spec, canonical) {
                         res += " (";
                         res += &parenth_anth
                             .title()
@@ -285,19 +1330,20 @@ impl Ieee {
                             .canonical
                             .format_title_case(&self.title_case);
 
-                        res.add_if_some(
-                            parenth_anth.issue().map(|i| i.to_string()),
-                            Some(", no. "),
-                            None,
-                        );
+                        // Some series are numbered by volume instead of issue.
+                        if let Some(issue) = parenth_anth.issue() {
+                            res += &format!(", no. {}", issue);
+                        } else if let Some(volume) = parenth_anth.volume() {
+                            res += ", ";
+                            res += &format_range("vol.", "vols.", volume);
+                        }
                         res += ")";
                     }
 
                     // And the conference series thing as well
                     let spec =
                         select!(Proceedings > ("p":(Proceedings | Anthology | Misc)));
-                    if let Some(mut bindings) = spec.apply(canonical) {
-                        let par_conf = bindings.remove("p").unwrap();
+                    if let Some(par_conf) = bound_parent(&spec, canonical) {
                         if let Some(parenth_title) = par_conf.title() {
                             res += " in ";
                             res += &parenth_title
@@ -313,7 +1359,7 @@ impl Ieee {
             entry.entry_type,
             Legislation | Repository | Video | Reference | Book | Proceedings | Anthology
         ) {
-            res.start_format(Formatting::Italic);
+            self.start_italic(&mut res);
 
             if entry.entry_type == Legislation {
                 res.add_if_some(entry.serial_number(), None, None);
@@ -324,74 +1370,124 @@ impl Ieee {
                     res += ", ";
                 }
 
-                res += &title.canonical.format_title_case(&self.title_case);
+                res += &self
+                    .truncate_title(title.canonical.format_title_case(&self.title_case));
             }
 
             res.commit_formats();
+
+            // The numbered series a book was published in
+            let spec = select!(Book > ("p":(Anthology["title"])));
+            if let Some(series) = bound_parent(&spec, entry) {
+                res += " (";
+                res += &series
+                    .title()
+                    .unwrap()
+                    .canonical
+                    .format_title_case(&self.title_case);
+                res.add_if_some(series.issue().map(|i| i.to_string()), Some(" "), None);
+                res += ")";
+            }
         } else if let Some(title) = entry.title() {
-            res += "“";
-            res += &title.canonical.format_sentence_case(&self.sentence_case);
-            res += ",”";
+            res += &self.locale.quotes.0;
+            res += &self.truncate_title(
+                title.canonical.format_sentence_case(&self.sentence_case),
+            );
+            res.push(',');
+            res += &self.locale.quotes.1;
         }
 
         res
     }
 
+    /// Determines which group of trailing elements an entry receives without
+    /// formatting it. This is useful to find out why an entry renders
+    /// differently than expected.
+    pub fn classify(&self, entry: &Entry) -> IeeeAddonBranch {
+        let canonical = get_canonical_parent(entry).unwrap_or(entry);
+        self.addon_branch(entry, canonical)
+    }
+
+    fn addon_branch(&self, entry: &Entry, canonical: &Entry) -> IeeeAddonBranch {
+        match (entry.entry_type, canonical.entry_type) {
+            (_, Conference) | (_, Proceedings) => IeeeAddonBranch::Proceedings,
+            (_, Reference) => IeeeAddonBranch::Reference,
+            (_, Repository) => IeeeAddonBranch::Repository,
+            (_, Video) => IeeeAddonBranch::Video,
+            (_, Patent) => IeeeAddonBranch::Patent,
+            (_, Periodical) if entry.early_access() == Some(&true) => {
+                IeeeAddonBranch::EarlyAccess
+            }
+            (_, Periodical)
+                if self.compact_periodicals && canonical.volume().is_some() =>
+            {
+                IeeeAddonBranch::CompactPeriodical
+            }
+            (_, Periodical) => IeeeAddonBranch::Periodical,
+            (_, Report) => IeeeAddonBranch::Report,
+            (_, Thesis) => IeeeAddonBranch::Thesis,
+            (_, Legislation) => IeeeAddonBranch::Legislation,
+            (_, Manuscript) => IeeeAddonBranch::Manuscript,
+            _ if preprint_parent(entry).is_some() => IeeeAddonBranch::Preprint,
+            (Web, _) | (Blog, _) => IeeeAddonBranch::Web,
+            _ if bound_parent(&select!(* > ("p":(Blog | Web))), entry).is_some() => {
+                IeeeAddonBranch::WebParented
+            }
+            // Types without a dedicated branch, e.g. artworks, audio
+            // recordings, and cases, are cited like books.
+            _ => IeeeAddonBranch::Generic,
+        }
+    }
+
     fn get_addons(
         &self,
         entry: &Entry,
         canonical: &Entry,
-        chapter: Option<u32>,
-        section: Option<u32>,
-        disamb: Option<usize>,
+        ctx: &PartContext,
+        events: &mut Vec<FormatEvent>,
     ) -> Vec<String> {
+        let (part, chapter, section, disamb) =
+            (ctx.part, ctx.chapter, ctx.section, ctx.disamb);
         let mut res = vec![];
-        let preprint =
-            select!((Article | Book | Anthos) > ("p": Repository)).apply(entry);
-        let web_parented = select!(* > ("p":(Blog | Web))).apply(entry);
+        let preprint = preprint_parent(entry);
+        let web_parented = bound_parent(&select!(* > ("p":(Blog | Web))), entry);
 
-        match (entry.entry_type, canonical.entry_type) {
-            (_, Conference) | (_, Proceedings) => {
+        match self.addon_branch(entry, canonical) {
+            IeeeAddonBranch::Proceedings => {
                 if canonical.entry_type == Proceedings {
                     if let Some(eds) = canonical.editors() {
-                        let mut al = self.and_list(name_list_straight(&eds));
-                        if eds.len() > 1 {
-                            al += ", Eds."
-                        } else {
-                            al += ", Ed."
-                        }
-                        res.push(al);
-                    }
-
-                    if let Some(vols) = entry.volume().or_else(|| canonical.volume()) {
-                        res.push(format_range("vol.", "vols.", &vols));
+                        res.push(self.editor_credit(eds).value);
                     }
 
-                    if let Some(ed) = canonical.edition() {
-                        match ed {
-                            NumOrStr::Number(i) => {
-                                if *i > 1 {
-                                    res.push(format!("{} ed.", en::get_ordinal(*i)));
-                                }
-                            }
-                            NumOrStr::Str(s) => res.push(s.clone()),
-                        }
-                    }
+                    res.extend(self.volume_and_edition(entry, canonical));
                 }
 
-                if let Some(loc) = canonical.location() {
-                    res.push(loc.value.clone());
+                if let Some(locs) = canonical.locations().filter(|l| !l.is_empty()) {
+                    let locs: Vec<_> = locs
+                        .iter()
+                        .map(|loc| {
+                            if self.normalize_locations {
+                                abbreviations::normalize_location(&loc.value)
+                            } else {
+                                loc.value.clone()
+                            }
+                        })
+                        .collect();
+                    res.push(locs.join("; "));
                 }
 
                 if canonical.entry_type != Conference || !self.show_url(entry) {
-                    if let Some(date) = entry.date_any() {
-                        res.push(format_date(date, disamb))
+                    if let Some(date) = self.date_element(entry, canonical, disamb) {
+                        res.push(date)
                     }
                 }
 
                 if canonical.entry_type == Conference {
+                    // Papers without a number are located by their pages.
                     if let Some(sn) = entry.serial_number() {
                         res.push(format!("Paper {}", sn));
+                    } else if let Some(pages) = entry.page_range() {
+                        res.push(format_range("p.", "pp.", pages));
                     }
                 } else {
                     if let Some(pages) = entry.page_range() {
@@ -399,86 +1495,109 @@ impl Ieee {
                     }
 
                     if let Some(doi) = entry.doi() {
-                        res.push(format!("doi: {}", doi));
+                        res.push(self.isolate_ltr(
+                            entry,
+                            canonical,
+                            self.format_doi(entry, doi),
+                        ));
+                        events.push(FormatEvent::UsedDoi);
                     }
                 }
             }
-            (_, Reference) => {
-                let has_url = self.show_url(entry);
-                let date = entry.date_any().map(|date| format_date(date, disamb));
-
-                if let Some(ed) = canonical.edition() {
-                    match ed {
-                        NumOrStr::Number(i) => {
-                            if *i > 1 {
-                                res.push(format!("{} ed.", en::get_ordinal(*i)));
-                            }
-                        }
-                        NumOrStr::Str(s) => res.push(s.clone()),
-                    }
+            IeeeAddonBranch::Reference => {
+                // Online reference works are printed like their print
+                // counterparts, the URL follows in the "[Online]" block.
+                if let Some(ed) = canonical.edition().and_then(format_edition) {
+                    res.push(ed);
                 }
 
-                if !has_url {
-                    if let Some(publisher) =
-                        canonical.organization().or_else(|| canonical.publisher().value())
-                    {
-                        res.push(publisher.into());
+                if let Some(publisher) = canonical
+                    .organization()
+                    .map(Into::into)
+                    .or_else(|| canonical.publishers().joined("; "))
+                {
+                    res.push(publisher);
 
-                        if let Some(loc) = canonical.location() {
-                            res.push(loc.value.clone());
-                        }
+                    if let Some(loc) = canonical.locations().joined("; ") {
+                        res.push(loc);
                     }
+                }
 
-                    if let Some(date) = date {
-                        res.push(date);
-                    }
+                if let Some(date) = self.date_element(entry, canonical, disamb) {
+                    res.push(date);
+                }
 
-                    if let Some(pages) = entry.page_range() {
-                        res.push(format_range("p.", "pp.", &pages));
-                    }
-                } else if let Some(date) = date {
-                    res.push(format!("({})", date));
+                if let Some(pages) = entry.page_range() {
+                    res.push(format_range("p.", "pp.", pages));
                 }
             }
-            (_, Repository) => {
+            IeeeAddonBranch::Repository => {
                 if let Some(sn) = canonical.serial_number() {
                     res.push(format!("(version {})", sn));
-                } else if let Some(date) = canonical.date().or_else(|| entry.date_any()) {
-                    res.push(format!("({})", date.year));
+                } else if let Some(date) = resolve_date(canonical, entry) {
+                    res.push(format!("({})", self.format_year(date)));
+                }
+
+                if let Some(commit) = entry.commit().or_else(|| canonical.commit()) {
+                    res.push(self.format_commit(commit));
                 }
 
-                if let Some(publisher) =
-                    canonical.publisher().value().or_else(|| canonical.organization())
+                if let Some(publisher) = canonical
+                    .publishers()
+                    .joined("; ")
+                    .or_else(|| canonical.organization().map(Into::into))
                 {
                     let mut publ = String::new();
-                    if let Some(location) = canonical.location() {
-                        publ += &location.value;
+                    if let Some(location) = canonical.locations().joined("; ") {
+                        publ += &location;
                         publ += ": ";
                     }
 
-                    publ += publisher;
+                    publ += &publisher;
 
-                    if let Some(lang) = entry.language().or_else(|| canonical.language())
+                    if let Some(lang) =
+                        language_annotation(&self.locale, entry, canonical, events)
                     {
-                        publ += " (in ";
-                        publ += Language::from_639_1(lang.language.as_str())
-                            .unwrap()
-                            .to_name();
-                        publ.push(')');
+                        publ += &format!(" ({})", lang);
                     }
 
                     res.push(publ);
                 }
+
+                // Datasets and software archives register DOIs as well, but
+                // they are only printed to show their agency.
+                let agency = entry.registration_agency().filter(|_| self.doi_agencies);
+                if let Some(doi) = entry.doi().filter(|_| agency.is_some()) {
+                    res.push(self.isolate_ltr(
+                        entry,
+                        canonical,
+                        self.format_doi(entry, doi),
+                    ));
+                    events.push(FormatEvent::UsedDoi);
+                }
             }
-            (_, Video) => {
-                if let Some(date) = canonical.date().or_else(|| entry.date_any()) {
-                    res.push(format!("({})", date.year));
+            IeeeAddonBranch::Video => {
+                if let Some(locator) = episode_locator(entry) {
+                    res.push(locator);
+                }
+
+                if let Some(date) = resolve_date(canonical, entry) {
+                    res.push(format!("({})", self.format_year(date)));
+                }
+
+                let mut subtitlers = entry.affiliated_with_role(PersonRole::Subtitler);
+                subtitlers.extend(entry.affiliated_with_role(PersonRole::Translator));
+                if !subtitlers.is_empty() {
+                    res.push(format!(
+                        "subtitled by {}",
+                        self.and_list(self.name_list(&subtitlers))
+                    ));
                 }
             }
-            (_, Patent) => {
+            IeeeAddonBranch::Patent => {
                 let mut start = String::new();
-                if let Some(location) = canonical.location() {
-                    start += &location.value;
+                if let Some(location) = canonical.locations().joined("; ") {
+                    start += &location;
                     start.push(' ');
                 }
 
@@ -490,19 +1609,15 @@ impl Ieee {
 
                 if self.show_url(entry) {
                     let mut fin = String::new();
-                    if let Some(date) = entry.date_any() {
+                    if let Some(date) = resolve_date(entry, canonical) {
                         fin += "(";
-                        fin += &date.display_year();
+                        fin += &self.format_year(date);
                         if let Some(month) = date.month {
                             fin += ", ";
                             fin += &(if let Some(day) = date.day {
-                                format!(
-                                    "{} {}",
-                                    en::get_month_abbr(month, true).unwrap(),
-                                    day + 1
-                                )
+                                format!("{} {}", self.month_abbr(month), day + 1)
                             } else {
-                                en::get_month_abbr(month, true).unwrap()
+                                self.month_abbr(month)
                             });
                         }
                         fin += "). ";
@@ -514,18 +1629,72 @@ impl Ieee {
                 } else {
                     res.push(start);
 
-                    if let Some(date) = entry.date_any() {
-                        res.push(format_date(date, disamb));
+                    if let Some(date) = self.date_element(entry, canonical, disamb) {
+                        res.push(date);
                     }
                 }
             }
-            (_, Periodical) => {
+            IeeeAddonBranch::EarlyAccess => {
+                res.push("early access".to_string());
+
+                if let Some(date) = self.date_element(entry, canonical, disamb) {
+                    res.push(date);
+                }
+
+                if let Some(doi) = entry.doi() {
+                    res.push(self.isolate_ltr(
+                        entry,
+                        canonical,
+                        self.format_doi(entry, doi),
+                    ));
+                    events.push(FormatEvent::UsedDoi);
+                }
+            }
+            IeeeAddonBranch::CompactPeriodical => {
+                let mut volume = format_range("", "", canonical.volume().unwrap());
+                if let Some(iss) = canonical.issue() {
+                    volume += &format!("({})", iss);
+                }
+                res.push(volume);
+
+                if let Some(pages) = entry.page_range() {
+                    res.push(format_range("p.", "pp.", pages));
+                } else if let Some(sn) = entry.serial_number() {
+                    res.push(format_article_id(sn));
+                }
+
+                if let Some(date) = self.date_element(entry, canonical, disamb) {
+                    res.push(date);
+                }
+
+                if let Some(doi) = entry.doi() {
+                    res.push(self.isolate_ltr(
+                        entry,
+                        canonical,
+                        self.format_doi(entry, doi),
+                    ));
+                    events.push(FormatEvent::UsedDoi);
+                }
+            }
+            IeeeAddonBranch::Periodical => {
                 if let Some(vols) = canonical.volume() {
                     res.push(format_range("vol.", "vols.", &vols));
                 }
 
+                // Issues without a number are designated by their season and
+                // year, which then replace the date.
+                let season = canonical
+                    .season()
+                    .filter(|_| canonical.issue().is_none() && entry.status().is_none())
+                    .and_then(|season| {
+                        resolve_date(entry, canonical)
+                            .map(|date| format!("{} {}", season, self.format_year(date)))
+                    });
+
                 if let Some(iss) = canonical.issue() {
                     res.push(format!("no. {}", iss));
+                } else if let Some(season) = &season {
+                    res.push(season.clone());
                 }
 
                 let pages = if let Some(pages) = entry.page_range() {
@@ -535,8 +1704,10 @@ impl Ieee {
                     false
                 };
 
-                if let Some(date) = entry.date_any() {
-                    res.push(format_date(date, disamb));
+                if season.is_none() {
+                    if let Some(date) = self.date_element(entry, canonical, disamb) {
+                        res.push(date);
+                    }
                 }
 
                 if !pages {
@@ -546,17 +1717,24 @@ impl Ieee {
                 }
 
                 if let Some(doi) = entry.doi() {
-                    res.push(format!("doi: {}", doi));
+                    res.push(self.isolate_ltr(
+                        entry,
+                        canonical,
+                        self.format_doi(entry, doi),
+                    ));
+                    events.push(FormatEvent::UsedDoi);
                 }
             }
-            (_, Report) => {
-                if let Some(publisher) =
-                    canonical.organization().or_else(|| canonical.publisher().value())
+            IeeeAddonBranch::Report => {
+                if let Some(publisher) = canonical
+                    .organization()
+                    .map(Into::into)
+                    .or_else(|| canonical.publishers().joined("; "))
                 {
-                    res.push(publisher.into());
+                    res.push(publisher);
 
-                    if let Some(location) = canonical.location() {
-                        res.push(location.value.clone());
+                    if let Some(location) = canonical.locations().joined("; ") {
+                        res.push(location);
                     }
                 }
 
@@ -564,7 +1742,7 @@ impl Ieee {
                     res.push(format!("Rep. {}", sn));
                 }
 
-                let date = entry.date_any().map(|date| format_date(date, disamb));
+                let date = self.date_element(entry, canonical, disamb);
 
                 if !self.show_url(entry) {
                     if let Some(date) = date.clone() {
@@ -586,17 +1764,17 @@ impl Ieee {
                     }
                 }
             }
-            (_, Thesis) => {
+            IeeeAddonBranch::Thesis => {
                 res.push("Thesis".to_string());
                 if let Some(org) = canonical.organization() {
                     res.push(if self.abbreviate_journals {
-                        abbreviations::abbreviate_journal(&org)
+                        self.abbreviate_journal(org)
                     } else {
                         org.to_string()
                     });
 
-                    if let Some(location) = canonical.location() {
-                        res.push(location.value.clone());
+                    if let Some(location) = canonical.locations().joined("; ") {
+                        res.push(location);
                     }
                 }
 
@@ -604,16 +1782,22 @@ impl Ieee {
                     res.push(sn.into());
                 }
 
-                if let Some(date) = entry.date_any() {
-                    res.push(date.display_year());
+                if let Some(status) = entry.status() {
+                    res.push(status.to_string());
+                } else if let Some(date) = resolve_date(entry, canonical) {
+                    res.push(self.with_original_date(
+                        entry,
+                        canonical,
+                        self.format_year(date),
+                    ));
                 }
             }
-            (_, Legislation) => {}
-            (_, Manuscript) => {
-                res.push("unpublished".to_string());
+            IeeeAddonBranch::Legislation => {}
+            IeeeAddonBranch::Manuscript => {
+                res.push(entry.status().unwrap_or("unpublished").to_string());
             }
-            _ if preprint.is_some() => {
-                let parent = preprint.unwrap().remove("p").unwrap();
+            IeeeAddonBranch::Preprint => {
+                let parent = preprint.unwrap();
                 if let Some(serial) = entry.serial_number() {
                     let mut sn = if let Some(url) = entry.url_any() {
                         let has_arxiv_serial = serial.to_lowercase().contains("arxiv");
@@ -647,87 +1831,106 @@ impl Ieee {
                     res.push(sn);
                 }
 
-                if let Some(date) = entry.date_any() {
-                    res.push(format_date(date, disamb));
+                if let Some(date) = self.date_element(entry, canonical, disamb) {
+                    res.push(date);
                 }
             }
-            (Web, _) | (Blog, _) => {
+            IeeeAddonBranch::Web => {
                 if let Some(publisher) = entry
-                    .publisher()
-                    .map(|publ| publ.value.as_str())
-                    .or_else(|| entry.organization())
+                    .publishers()
+                    .joined("; ")
+                    .or_else(|| entry.organization().map(Into::into))
                 {
-                    res.push(publisher.into());
+                    res.push(publisher);
                 }
             }
-            _ if web_parented.is_some() => {
-                let parent = web_parented.unwrap().remove("p").unwrap();
+            IeeeAddonBranch::WebParented => {
+                let parent = web_parented.unwrap();
                 if let Some(publisher) = parent
                     .title()
-                    .map(|t| &t.canonical)
-                    .or_else(|| parent.publisher())
-                    .or_else(|| entry.publisher())
-                    .value()
-                    .or_else(|| parent.organization())
-                    .or_else(|| entry.organization())
+                    .map(|t| t.canonical.value.clone())
+                    .or_else(|| parent.publishers().joined("; "))
+                    .or_else(|| entry.publishers().joined("; "))
+                    .or_else(|| parent.organization().map(Into::into))
+                    .or_else(|| entry.organization().map(Into::into))
                 {
-                    res.push(publisher.into());
+                    res.push(publisher);
+                }
+
+                if let Some(eds) = parent.editors().filter(|eds| !eds.is_empty()) {
+                    res.push(self.editor_credit(eds).value);
                 }
             }
-            _ => {
-                if let (Some(_), Some(eds)) = (
-                    entry.authors().unwrap_or_default().get(0),
-                    entry.editors().or_else(|| canonical.editors()),
-                ) {
-                    let mut al = self.and_list(name_list_straight(&eds));
-                    if eds.len() > 1 {
-                        al += ", Eds."
+            IeeeAddonBranch::Generic => {
+                // Editors of the container are never used as the author
+                // element, so they always belong here.
+                let has_authors = !entry.authors().unwrap_or_default().is_empty();
+                let container_eds = entry != canonical && entry.editors().is_none();
+                if self.lead_editors(entry, canonical).is_some() {
+                    let authors = entry.authors().unwrap_or_default();
+                    res.push(format!("by {}", self.and_list(self.name_list(authors))));
+                } else if let Some(eds) = entry
+                    .editors()
+                    .or_else(|| canonical.editors())
+                    .filter(|_| has_authors || container_eds)
+                    .filter(|_| self.leading_editors(entry, canonical).is_none())
+                {
+                    if self.edited_by && has_authors && entry.editors().is_some() {
+                        res.push(format!("edited by {}", self.editor_list(eds).value));
                     } else {
-                        al += ", Ed."
+                        res.push(self.editor_credit(eds).value);
                     }
-                    res.push(al);
                 }
 
-                if let Some(vols) = entry.volume().or_else(|| canonical.volume()) {
-                    res.push(format_range("vol.", "vols.", &vols));
-                }
-
-                if let Some(ed) = canonical.edition() {
-                    match ed {
-                        NumOrStr::Number(i) => {
-                            if *i > 1 {
-                                res.push(format!("{} ed.", en::get_ordinal(*i)));
-                            }
-                        }
-                        NumOrStr::Str(s) => res.push(s.clone()),
-                    }
-                }
+                res.extend(self.volume_and_edition(entry, canonical));
 
-                if let Some(publisher) =
-                    canonical.publisher().value().or_else(|| canonical.organization())
+                if let Some(publisher) = canonical
+                    .publishers()
+                    .joined("; ")
+                    .or_else(|| canonical.organization().map(Into::into))
                 {
                     let mut publ = String::new();
-                    if let Some(location) = canonical.location() {
-                        publ += &location.value;
+                    if let Some(location) = canonical.locations().joined("; ") {
+                        publ += &location;
                         publ += ": ";
                     }
 
                     publ += &publisher;
 
-                    if let Some(lang) = entry.language().or_else(|| canonical.language())
+                    if let Some(lang) =
+                        language_annotation(&self.locale, entry, canonical, events)
                     {
-                        publ += " (in ";
-                        publ += Language::from_639_1(lang.language.as_str())
-                            .unwrap()
-                            .to_name();
-                        publ.push(')');
+                        publ += &format!(" ({})", lang);
                     }
 
                     res.push(publ);
                 }
 
-                if let Some(date) = canonical.date_any() {
-                    res.push(date.display_year());
+                if let Some(status) = entry.status() {
+                    res.push(status.to_string());
+                } else if let Some(date) = resolve_date(canonical, entry) {
+                    res.push(self.with_original_date(
+                        entry,
+                        canonical,
+                        self.format_year(date),
+                    ));
+                }
+
+                if self.page_totals
+                    && entry == canonical
+                    && entry.entry_type == Book
+                    && entry.page_range().is_none()
+                {
+                    if let Some(total) = entry.page_total() {
+                        res.push(match entry.front_matter_pages() {
+                            Some(front) => format!("{} + {} pp.", front, total),
+                            None => format!("{} pp.", total),
+                        });
+                    }
+                }
+
+                if let Some(part) = part {
+                    res.push(part.to_string());
                 }
 
                 if let Some(chapter) = chapter {
@@ -744,17 +1947,65 @@ impl Ieee {
             }
         }
 
+        res.extend(self.format_identifiers(entry));
+
+        // Fallbacks between the entry and its parent can yield the same value
+        // twice in a row.
+        res.dedup();
         res
     }
 
+    /// Formats an entry as a reference with an explicitly given container.
+    ///
+    /// Unlike [`BibliographyStyle::reference`], this does not try to find
+    /// the container among the entry's parents but uses `parent` or, if it is
+    /// `None`, the entry itself.
+    pub fn format_with_parent<'a>(
+        &self,
+        entry: &'a Entry,
+        parent: Option<&Entry>,
+    ) -> DisplayReference<'a> {
+        let chapter = if entry.entry_type == Chapter {
+            entry.serial_number().and_then(|sn| sn.parse().ok())
+        } else {
+            None
+        };
+
+        let record = Record::from_entry(entry);
+        let ctx = PartContext {
+            part: None,
+            chapter,
+            section: None,
+            disamb: record.disambiguation,
+        };
+        self.format_parts(&record, entry, parent.unwrap_or(entry), ctx, &mut vec![])
+            .0
+    }
+
+    /// Formats a reference like [`BibliographyStyle::reference`] and records
+    /// which optional fields were used or skipped in `events`.
+    pub fn format_with_diagnostics<'a>(
+        &self,
+        record: &Record<'a>,
+        events: &mut Vec<FormatEvent>,
+    ) -> DisplayReference<'a> {
+        self.get_single_record(record, events).0
+    }
+
     fn get_single_record<'a>(
         &self,
         record: &Record<'a>,
+        events: &mut Vec<FormatEvent>,
     ) -> (DisplayReference<'a>, Vec<Person>) {
         let mut entry = record.entry;
         let mut parent = entry.parents().and_then(|v| v.first());
         let mut sn_stack = vec![];
-        while entry.title().is_none() && select!(Chapter | Scene).matches(entry) {
+        let mut depth = 0;
+        while entry.title().is_none()
+            && select!(Chapter | Scene).matches(entry)
+            && depth < MAX_PARENT_DEPTH
+        {
+            depth += 1;
             if let Some(sn) = entry.serial_number() {
                 sn_stack.push(sn);
             }
@@ -772,6 +2023,7 @@ impl Ieee {
             }
         }
 
+        let part = sn_stack.iter().copied().find(|s| is_part(s));
         let secs = sn_stack
             .into_iter()
             .map(|s| str::parse::<u32>(&s))
@@ -782,44 +2034,68 @@ impl Ieee {
         let chapter = secs.get(0).copied();
         let section = if secs.len() > 1 { secs.last().copied() } else { None };
 
-        let url = self.show_url(entry);
+        let canonical = get_canonical_parent(entry).unwrap_or(entry);
+        let ctx = PartContext {
+            part,
+            chapter,
+            section,
+            disamb: record.disambiguation,
+        };
+        self.format_parts(record, entry, canonical, ctx, events)
+    }
 
-        let parent = get_canonical_parent(entry);
-        let canonical = parent.unwrap_or(entry);
+    fn format_parts<'a>(
+        &self,
+        record: &Record<'a>,
+        entry: &Entry,
+        canonical: &Entry,
+        ctx: PartContext,
+        events: &mut Vec<FormatEvent>,
+    ) -> (DisplayReference<'a>, Vec<Person>) {
+        let url = self.show_url(entry);
 
         let (authors, al) = self.get_author(entry, canonical);
-        let title = self.get_title_element(entry, canonical);
-        let addons =
-            self.get_addons(entry, canonical, chapter, section, record.disambiguation);
+        let title = self.get_title_element(entry, canonical, authors.is_empty(), events);
+        let addons = self.get_addons(entry, canonical, &ctx, events);
+
+        if entry.status().is_none() && resolve_date(entry, canonical).is_none() {
+            events.push(FormatEvent::MissingDate);
+        }
 
-        let mut res = DisplayString::from_string(authors);
+        let mut res = authors;
 
         if canonical.entry_type == Legislation {
             if let Some(NumOrStr::Str(session)) = entry.edition() {
-                if !res.is_empty() {
-                    res += ". ";
-                }
+                push_comma_quote_aware_with(
+                    &mut res.value,
+                    '.',
+                    true,
+                    &self.locale.quotes.1,
+                );
                 res += session;
             }
         }
 
         if canonical.entry_type == Video {
-            if let Some(location) = canonical.location() {
+            if let Some(location) = canonical.locations().joined("; ") {
                 if !res.is_empty() {
                     res += ", ";
                 }
-                res += &location.value;
+                res += &location;
             }
         } else if canonical.entry_type == Legislation
             || ((canonical.entry_type == Conference || canonical.entry_type == Patent)
                 && url)
         {
-            if let Some(date) = entry.date_any() {
-                if !res.is_empty() {
-                    res += ". ";
-                }
+            if let Some(date) = resolve_date(entry, canonical) {
+                push_comma_quote_aware_with(
+                    &mut res.value,
+                    '.',
+                    true,
+                    &self.locale.quotes.1,
+                );
                 res.push('(');
-                res += &format_date(&date, record.disambiguation);
+                res += &self.format_date(date, record.disambiguation);
                 res.push(')');
             }
         }
@@ -831,21 +2107,24 @@ impl Ieee {
                     || canonical.entry_type == Patent)
                     && url)
             {
-                res += ". ";
+                // Author lists that end with "et al." already have a period.
+                push_comma_quote_aware_with(
+                    &mut res.value,
+                    '.',
+                    true,
+                    &self.locale.quotes.1,
+                );
             } else {
                 res += ", ";
             }
         }
         res += title;
 
-        let cur_len = res.len();
-        if cur_len > 4
-            && res.value.is_char_boundary(cur_len - 4)
-            && &res.value[cur_len - 4..] == ",”"
-        {
+        let tucked_comma = format!(",{}", self.locale.quotes.1);
+        if res.value.ends_with(&tucked_comma) {
             if addons.is_empty() {
-                res.value = (&res.value[..cur_len - 4]).into();
-                res.value += "”";
+                res.value.truncate(res.len() - tucked_comma.len());
+                res += &self.locale.quotes.1;
             } else {
                 res.push(' ');
             }
@@ -853,6 +2132,11 @@ impl Ieee {
             res += ", ";
         }
 
+        let ends_with_doi = matches!(
+            (entry.doi(), addons.last()),
+            (Some(doi), Some(last)) if last.contains(doi)
+        );
+
         let addon_count = addons.len();
         for (index, addon) in addons.into_iter().enumerate() {
             res += &addon;
@@ -861,39 +2145,107 @@ impl Ieee {
             }
         }
 
-        push_comma_quote_aware(&mut res.value, '.', false);
+        match self.doi_period {
+            IeeeDoiPeriod::Spaced if ends_with_doi => res += " .",
+            _ => push_comma_quote_aware_with(
+                &mut res.value,
+                '.',
+                false,
+                &self.locale.quotes.1,
+            ),
+        }
+
+        // The period after the DOI can only be omitted once it is clear that
+        // nothing follows it.
+        let doi_period_end = (ends_with_doi && self.doi_period == IeeeDoiPeriod::Omitted)
+            .then(|| res.len());
+
+        if self.preprint_label && preprint_parent(entry).is_some() {
+            if !res.is_empty() {
+                res += " ";
+            }
+            res += "[Preprint].";
+        }
+
+        // The physical medium of a recording replaces the "[Online Video]"
+        // designator or, if there is no URL, ends the reference.
+        let medium = entry
+            .medium()
+            .or_else(|| canonical.medium())
+            .filter(|_| matches!(canonical.entry_type, Video | Audio));
+        if let Some(medium) = medium.filter(|_| !url) {
+            if !res.is_empty() {
+                res += " ";
+            }
+            res += &format!("[{}].", medium);
+        }
 
         if url {
-            if let Some(url) = entry.url_any() {
+            // The archived snapshot replaces the live URL if it is dead.
+            let archive = entry.archive_url();
+            let live = entry
+                .url_any()
+                .filter(|_| archive.is_none() || entry.dead_url() != Some(&true));
+            if let Some(url) = live.or(archive) {
+                events.push(FormatEvent::UsedUrl);
+                let isolate = self.isolates_ltr(entry, canonical);
                 if !res.is_empty() {
                     res += " ";
                 }
 
                 if canonical.entry_type != Web && canonical.entry_type != Blog {
-                    if let Some(date) = &url.visit_date {
-                        res += &format!("Accessed: {}. ", format_date(&date, None));
+                    if let Some(date) = self.visit_date(url) {
+                        res += &format!("Accessed: {}. ", self.format_date(date, None));
                     }
 
-                    if canonical.entry_type == Video {
+                    if let Some(medium) = medium {
+                        res += &format!("[{}]", medium);
+                    } else if canonical.entry_type == Video {
                         res += "[Online Video]";
                     } else {
                         res += "[Online]";
                     }
 
                     res += ". Available: ";
-                    res.start_format(Formatting::Link(url.value.to_string()));
-                    res += url.value.as_str();
-                    res.commit_formats();
+                    self.push_url(&mut res, url, isolate);
+
+                    if let Some(access_note) = entry.access_note() {
+                        res += &format!(" ({})", access_note);
+                    }
                 } else {
-                    res.start_format(Formatting::Link(url.value.to_string()));
-                    res += url.value.as_str();
-                    res.commit_formats();
+                    self.push_url(&mut res, url, isolate);
+
+                    // The access note shares the parenthetical of the access
+                    // date so that it does not trail the closing period.
+                    let date = self.visit_date(url);
+                    let mut parenthetical: Vec<String> = date
+                        .map(|date| format!("accessed: {}", self.format_date(date, None)))
+                        .into_iter()
+                        .collect();
+                    parenthetical.extend(entry.access_note().map(Into::into));
+
+                    if !parenthetical.is_empty() {
+                        res += &format!(" ({})", parenthetical.join("; "));
+                    }
 
-                    if let Some(date) = &url.visit_date {
-                        res += &format!(" (accessed: {}).", format_date(&date, None));
+                    if date.is_some() {
+                        res.push('.');
                     }
                 }
+
+                if let (Some(_), Some(archive)) = (live, archive) {
+                    res += " Archived at: ";
+                    self.push_url(&mut res, archive, isolate);
+                }
+            }
+        }
+
+        if let Some(count) = entry.cited_by().filter(|_| self.cited_by_counts) {
+            if !res.is_empty() {
+                res += " ";
             }
+
+            res += &format!("(cited by {})", count);
         }
 
         if let Some(note) = entry.note() {
@@ -904,6 +2256,10 @@ impl Ieee {
             res += &format!("({})", note);
         }
 
+        if doi_period_end == Some(res.len()) {
+            res.value.pop();
+        }
+
         (
             DisplayReference::new(
                 record.entry,
@@ -915,22 +2271,61 @@ impl Ieee {
     }
 }
 
-fn format_date(date: &Date, disamb: Option<usize>) -> String {
-    let mut res = String::new();
-    if let Some(month) = date.month {
-        res += &(if let Some(day) = date.day {
-            format!("{} {},", en::get_month_abbr(month, true).unwrap(), day + 1)
-        } else {
-            en::get_month_abbr(month, true).unwrap()
-        });
-        res += " ";
+/// Appends pointers like "(see also [3], [7])" to the numbered references
+/// of the entries in the `see-also` field of an entry.
+fn push_see_also(res: &mut DisplayString, entry: &Entry, db: &Database) {
+    let numbers = entry
+        .see_also()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| db.number_of(key))
+        .map(|n| format!("[{}]", n))
+        .collect::<Vec<_>>();
+
+    if numbers.is_empty() {
+        return;
+    }
+
+    if !res.is_empty() {
+        *res += " ";
     }
+    *res += &format!("(see also {})", numbers.join(", "));
+}
+
+/// Resolves the date of a reference, consulting `primary` and its ancestors
+/// first and falling back to `secondary` and its ancestors.
+///
+/// Most branches pass the entry first and the canonical parent second while
+/// branches where the container's date takes precedence swap the arguments.
+///
+/// If none of them has a `date`, the `deposited-date` of `primary` and then
+/// that of `secondary` is used instead.
+fn resolve_date<'a>(primary: &'a Entry, secondary: &'a Entry) -> Option<&'a Date> {
+    primary
+        .date_any()
+        .or_else(|| secondary.date_any())
+        .or_else(|| primary.deposited_date())
+        .or_else(|| secondary.deposited_date())
+}
+
+/// Formats an edition. Numeric editions are ordinalized and the first edition
+/// is omitted; all other strings are passed through verbatim.
+fn format_edition(edition: &NumOrStr) -> Option<String> {
+    let ordinal = |i: i64| {
+        if i > 1 {
+            Some(format!("{} ed.", en::get_ordinal(i)))
+        } else {
+            None
+        }
+    };
 
-    res += &date.display_year();
-    if let Some(disamb) = disamb {
-        res.push(alph_designator(disamb).to_ascii_uppercase());
+    match edition {
+        NumOrStr::Number(i) => ordinal(*i),
+        NumOrStr::Str(s) => match s.trim().parse::<i64>() {
+            Ok(i) => ordinal(i),
+            Err(_) => Some(s.clone()),
+        },
     }
-    res
 }
 
 impl<'a> BibliographyStyle<'a> for Ieee {
@@ -942,17 +2337,2753 @@ impl<'a> BibliographyStyle<'a> for Ieee {
         let mut items = vec![];
 
         for record in db.records() {
-            items.push(self.get_single_record(record));
+            let (mut reference, persons) = self.get_single_record(record, &mut vec![]);
+            if self.see_also {
+                push_see_also(&mut reference.display, record.entry, db);
+            }
+            items.push((reference, persons));
         }
 
         sorted_bibliography(items, ordering)
     }
 
     fn reference(&self, record: &Record<'a>) -> DisplayReference<'a> {
-        self.get_single_record(record).0
+        self.get_single_record(record, &mut vec![]).0
     }
 
     fn ordering(&self) -> BibliographyOrdering {
         BibliographyOrdering::ByNumericPrefix
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::from_yaml_str;
+    use crate::types::Title;
+
+    fn format(ieee: &Ieee, yaml: &str) -> String {
+        let entries = from_yaml_str(yaml).unwrap();
+        ieee.reference(&Record::from_entry(&entries[0])).display.value
+    }
+
+    #[test]
+    fn original_date() {
+        let yaml = r#"
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            date: 1998
+            original-date: 1925
+            publisher: Schocken Books
+            location: New York
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "F. Kafka, The Trial, New York: Schocken Books, 1998."
+        );
+
+        let mut ieee = Ieee::new();
+        ieee.show_original_date = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "F. Kafka, The Trial, New York: Schocken Books, 1998 (orig. pub. 1925)."
+        );
+    }
+
+    #[test]
+    fn date_fallback() {
+        let parent_dated = r#"
+        chapter:
+            type: Chapter
+            title: The first chapter
+            author: Doe, Jane
+            parent:
+                type: Book
+                title: Collected essays
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let child_dated = r#"
+        chapter:
+            type: Chapter
+            title: The first chapter
+            author: Doe, Jane
+            date: 2001
+            parent:
+                type: Book
+                title: Collected essays
+                publisher: ACME
+        "#;
+
+        let expected = "J. Doe, “The first chapter,” in Collected Essays, ACME, 2001.";
+        assert_eq!(format(&Ieee::new(), parent_dated), expected);
+        assert_eq!(format(&Ieee::new(), child_dated), expected);
+    }
+
+    #[test]
+    fn editions() {
+        let book = |edition: &str| {
+            let yaml = format!(
+                r#"
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            date: 1998
+            publisher: Schocken Books
+            edition: {}
+        "#,
+                edition
+            );
+            format(&Ieee::new(), &yaml)
+        };
+
+        assert_eq!(book("1"), "F. Kafka, The Trial, Schocken Books, 1998.");
+        assert_eq!(book("2"), "F. Kafka, The Trial, 2nd ed., Schocken Books, 1998.");
+        assert_eq!(
+            book("revised"),
+            "F. Kafka, The Trial, revised, Schocken Books, 1998."
+        );
+        assert_eq!(
+            book("2nd rev."),
+            "F. Kafka, The Trial, 2nd rev., Schocken Books, 1998."
+        );
+    }
+
+    #[test]
+    fn co_publishers() {
+        let yaml = r#"
+        handbook:
+            type: Book
+            title: Handbook of robotics
+            editor: Siciliano, Bruno
+            date: 2016
+            publisher: [Springer, MIT Press]
+            location: Cambridge, MA
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "B. Siciliano, Ed., Handbook of Robotics, Cambridge, MA: Springer; MIT Press, 2016."
+        );
+    }
+
+    #[test]
+    fn multiple_locations() {
+        let yaml = r#"
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            date: 1998
+            publisher: Schocken Books
+            location: [New York, London]
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "F. Kafka, The Trial, New York; London: Schocken Books, 1998."
+        );
+    }
+
+    #[test]
+    fn persistent_url() {
+        let yaml = r#"
+        kinetics:
+            type: Report
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            url:
+                value: https://doi.org/10.1103/PhysRevB.101.125202
+                date: 2021-01-07
+        "#;
+
+        let res = format(&Ieee::new(), yaml);
+        assert!(!res.contains("Accessed"), "{}", res);
+        assert!(res.contains("[Online]. Available: https://doi.org/"), "{}", res);
+
+        let mut ieee = Ieee::new();
+        ieee.persistent_hosts.clear();
+        assert!(format(&ieee, yaml).contains("Accessed: Jan. 7, 2021."));
+    }
+
+    #[test]
+    fn with_contributor() {
+        let yaml = r#"
+        memoir:
+            type: Book
+            title: My life in code
+            author: Smith, John
+            affiliated:
+                role: Contributor
+                names: Lee, Kim
+            date: 2010
+            publisher: ACME
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "J. Smith, with K. Lee, My Life in Code, ACME, 2010."
+        );
+    }
+
+    #[test]
+    fn unbound_parent() {
+        let yaml = r#"
+        preprint:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            parent:
+                type: Repository
+                title: arXiv
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let entry = &entries[0];
+
+        assert!(bound_parent(&select!(Article > Repository), entry).is_none());
+        assert!(bound_parent(&select!(Article > ("p": Repository)), entry).is_some());
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "T. D. Doan, “Kinetics of excitations,” 2020."
+        );
+    }
+
+    #[test]
+    fn capitalized_in() {
+        let yaml = r#"
+        poem:
+            type: Anthos
+            parent:
+                type: Anthology
+                title: Collected essays
+                publisher: ACME
+                date: 2001
+        "#;
+
+        assert_eq!(format(&Ieee::new(), yaml), "In Collected Essays, ACME, 2001.");
+
+        let chapter = r#"
+        chapter:
+            type: Chapter
+            title: The first chapter
+            author: Doe, Jane
+            parent:
+                type: Book
+                title: Collected essays
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert!(format(&ieee, chapter).contains("chapter,” in Collected"));
+        ieee.capitalize_in = true;
+        assert!(format(&ieee, chapter).contains("chapter,” In Collected"));
+    }
+
+    #[test]
+    fn volume_title() {
+        let yaml = r#"
+        works:
+            type: Book
+            title: The collected works
+            author: Woolf, Virginia
+            volume: 3
+            volume-title: late essays
+            date: 1967
+            publisher: Hogarth Press
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "V. Woolf, The Collected Works, vol. 3: Late Essays, Hogarth Press, 1967."
+        );
+    }
+
+    #[test]
+    fn chapter_authors() {
+        let chapter = |author: &str| {
+            let yaml = format!(
+                r#"
+        chapter:
+            type: Chapter
+            title: Neural networks
+            {}
+            parent:
+                type: Book
+                title: Handbook of learning
+                author: Wrong, Author
+                editor: ["Smith, John", "Lee, Kim"]
+                publisher: ACME
+                date: 2001
+        "#,
+                author
+            );
+            format(&Ieee::new(), &yaml)
+        };
+
+        assert_eq!(
+            chapter("author: Doe, Jane"),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith and K. Lee, Eds., \
+             ACME, 2001."
+        );
+        assert_eq!(
+            chapter(""),
+            "“Neural networks,” in Handbook Learn., J. Smith and K. Lee, Eds., ACME, 2001."
+        );
+    }
+
+    #[test]
+    fn truncated_titles() {
+        let yaml = r#"
+        deep:
+            type: Article
+            title: Deep learning for the detection of anomalies
+            author: Doe, Jane
+            date: 2019
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.max_title_chars = Some(45);
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Deep learning for the detection of anomalies,” 2019."
+        );
+
+        ieee.max_title_chars = Some(20);
+        assert_eq!(format(&ieee, yaml), "J. Doe, “Deep learning for…,” 2019.");
+    }
+
+    #[test]
+    fn deep_parents() {
+        let mut book = Entry::new("book", Book);
+        book.set_title(Title::new("The Trial"));
+
+        let mut entry = book;
+        for i in 0..100 {
+            let mut chapter = Entry::new("chapter", Chapter);
+            chapter.set_serial_number(i.to_string());
+            chapter.set_parents(vec![entry]);
+            entry = chapter;
+        }
+
+        let res = Ieee::new().reference(&Record::from_entry(&entry)).display.value;
+        assert!(!res.contains("The Trial"), "{}", res);
+    }
+
+    #[test]
+    fn book_series() {
+        let yaml = r#"
+        algorithms:
+            type: Book
+            title: Algorithms and data structures
+            author: Doe, Jane
+            date: 1997
+            publisher: Springer
+            parent:
+                type: Anthology
+                title: Lecture notes in computer science
+                issue: 1234
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "J. Doe, Algorithms and Data Structures (Lecture Notes in Computer Science \
+             1234), Springer, 1997."
+        );
+    }
+
+    #[test]
+    fn explicit_parent() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: The first chapter
+            author: Doe, Jane
+            parent:
+                type: Book
+                title: Collected essays
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let chapter = &entries[0];
+        let book = &chapter.parents().unwrap()[0];
+        let ieee = Ieee::new();
+
+        assert_eq!(
+            ieee.format_with_parent(chapter, Some(book)).display.value,
+            format(&ieee, yaml)
+        );
+        assert_eq!(
+            ieee.format_with_parent(chapter, None).display.value,
+            "J. Doe, “The first chapter,” 2001."
+        );
+    }
+
+    #[test]
+    fn italic_et_al() {
+        let yaml = r#"
+        big:
+            type: Article
+            title: A large collaboration
+            author: ["A, A", "B, B", "C, C", "D, D", "E, E", "F, F"]
+            date: 2020
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let record = Record::from_entry(&entries[0]);
+
+        let mut ieee = Ieee::new();
+        let roman = ieee.reference(&record).display;
+        assert_eq!(roman.value, "A. A, B. B, et al., “A large collaboration,” 2020.");
+        assert!(roman.formatting.is_empty());
+
+        ieee.italic_et_al = true;
+        let italic = ieee.reference(&record).display;
+        assert_eq!(italic.value, roman.value);
+        assert_eq!(italic.formatting, vec![(12..18, Formatting::Italic)]);
+    }
+
+    #[test]
+    fn duplicate_addons() {
+        let yaml = r#"
+        report:
+            type: Report
+            title: Global health statistics
+            organization: Geneva
+            location: Geneva
+            date: 2019
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "“Global health statistics,” Geneva, 2019."
+        );
+    }
+
+    #[test]
+    fn edited_by() {
+        let yaml = r#"
+        letters:
+            type: Book
+            title: Collected letters
+            author: Woolf, Virginia
+            editor: ["Nicolson, Nigel", "Trautmann, Joanne"]
+            date: 1975
+            publisher: Hogarth Press
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "V. Woolf, Collected Letters, N. Nicolson and J. Trautmann, Eds., \
+             Hogarth Press, 1975."
+        );
+
+        ieee.edited_by = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "V. Woolf, Collected Letters, edited by N. Nicolson and J. Trautmann, \
+             Hogarth Press, 1975."
+        );
+    }
+
+    #[test]
+    fn full_first_author() {
+        let yaml = r#"
+        paper:
+            type: Article
+            title: On graphs
+            author: ["Smith, John", "Lee, Kim", "Roe, Richard"]
+            date: 2020
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.full_first_author = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "Smith, John, K. Lee, and R. Roe, “On graphs,” 2020."
+        );
+    }
+
+    #[test]
+    fn initial_abbreviation() {
+        let yaml = r#"
+        paper:
+            type: Article
+            title: On graphs
+            author: ["Smith, John", "Lee, Kim"]
+            editor: Roe, Richard
+            date: 2020
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Smith and K. Lee, “On graphs,” R. Roe, Ed., 2020."
+        );
+
+        ieee.initial_abbreviation = InitialAbbreviation::Never;
+        assert_eq!(
+            format(&ieee, yaml),
+            "John Smith and Kim Lee, “On graphs,” Richard Roe, Ed., 2020."
+        );
+
+        ieee.initial_abbreviation = InitialAbbreviation::AllButFirst;
+        assert_eq!(
+            format(&ieee, yaml),
+            "Smith, John and K. Lee, “On graphs,” Roe, Richard, Ed., 2020."
+        );
+
+        ieee.initials = IeeeInitials::NoPeriods;
+        assert_eq!(
+            format(&ieee, yaml),
+            "Smith, John and K Lee, “On graphs,” Roe, Richard, Ed., 2020."
+        );
+    }
+
+    #[test]
+    fn access_date_visibility() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            url:
+                value: https://doi.org/10.1103/PhysRevB.101.125202
+                date: 2021-01-07
+            parent:
+                type: Periodical
+                title: Physical Review B
+        "#;
+
+        let mut ieee = Ieee::new();
+        let accessed = |ieee: &Ieee, yaml: &str| {
+            format(ieee, yaml).contains("Accessed: Jan. 7, 2021.")
+        };
+        let plain = yaml.replace(
+            "https://doi.org/10.1103/PhysRevB.101.125202",
+            "https://example.org/kinetics",
+        );
+
+        assert_eq!(ieee.access_dates, IeeeAccessDateVisibility::NonPersistent);
+        assert!(!accessed(&ieee, yaml));
+        assert!(accessed(&ieee, &plain));
+
+        ieee.access_dates = IeeeAccessDateVisibility::Always;
+        assert!(accessed(&ieee, yaml));
+        assert!(accessed(&ieee, &plain));
+
+        ieee.access_dates = IeeeAccessDateVisibility::Never;
+        assert!(!accessed(&ieee, yaml));
+        assert!(!accessed(&ieee, &plain));
+
+        let never = format(&ieee, &plain);
+        ieee.access_dates = IeeeAccessDateVisibility::NonPersistent;
+        assert_ne!(format(&ieee, &plain), never);
+    }
+
+    #[test]
+    fn compact_periodicals() {
+        let yaml = r#"
+        plos:
+            type: Article
+            title: Gene expression in zebrafish
+            author: Doe, Jane
+            date: 2021
+            serial-number: e0123456
+            parent:
+                type: Periodical
+                title: Biology Letters
+                volume: 5
+                issue: 2
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.compact_periodicals = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Gene expression in zebrafish,” Biol. Lett., 5(2), e0123456, 2021."
+        );
+
+        let numbered = yaml.replace("e0123456", "42");
+        assert_eq!(
+            format(&ieee, &numbered),
+            "J. Doe, “Gene expression in zebrafish,” Biol. Lett., 5(2), art. 42, 2021."
+        );
+
+        ieee.compact_periodicals = false;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Gene expression in zebrafish,” Biol. Lett., vol. 5, no. 2, \
+             2021, Art. no. e0123456."
+        );
+    }
+
+    #[test]
+    fn doi_prefix() {
+        let yaml = r#"
+        mohan:
+            type: Article
+            title: Effective heart disease prediction
+            author: Mohan, Senthilkumar
+            date: 2019
+            doi: 10.1109/ACCESS.2019.2923707
+            parent:
+                type: Periodical
+                title: IEEE Access
+                volume: 7
+        "#;
+
+        let mut ieee = Ieee::new();
+        let start =
+            "S. Mohan, “Effective heart disease prediction,” IEEE Access, vol. 7, 2019, ";
+
+        assert_eq!(ieee.doi_prefix, IeeeDoiPrefix::Label);
+        assert_eq!(
+            format(&ieee, yaml),
+            format!("{}doi: 10.1109/ACCESS.2019.2923707.", start)
+        );
+
+        ieee.doi_prefix = IeeeDoiPrefix::Url;
+        assert_eq!(
+            format(&ieee, yaml),
+            format!("{}https://doi.org/10.1109/ACCESS.2019.2923707.", start)
+        );
+
+        ieee.doi_prefix = IeeeDoiPrefix::Bare;
+        assert_eq!(format(&ieee, yaml), format!("{}10.1109/ACCESS.2019.2923707.", start));
+    }
+
+    #[test]
+    fn early_access() {
+        let yaml = r#"
+        kim:
+            type: Article
+            title: Low-power neural accelerators
+            author: Kim, Minsu
+            date: 2022
+            early-access: true
+            doi: 10.1109/TC.2022.3141592
+            parent:
+                type: Periodical
+                title: IEEE Transactions on Computers
+                volume: 71
+                issue: 4
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "M. Kim, “Low-power neural accelerators,” IEEE Trans. Comput., early \
+             access, 2022, doi: 10.1109/TC.2022.3141592."
+        );
+    }
+
+    #[test]
+    fn compilers() {
+        let yaml = r#"
+        folk:
+            type: Anthology
+            title: Songs of the Appalachian Mountains
+            date: 1952
+            publisher: Folkways
+            affiliated:
+                - role: Compiler
+                  names: ["Lomax, Alan", "Seeger, Peggy"]
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "A. Lomax and P. Seeger, Comps., Songs of the Appalachian Mountains, \
+             Folkways, 1952."
+        );
+    }
+
+    #[test]
+    fn month_abbreviations() {
+        let yaml = r#"
+        report:
+            type: Report
+            title: Annual report
+            organization: ACME
+            date: 2020-09-14
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(format(&ieee, yaml), "“Annual report,” ACME, Sep. 14, 2020.");
+
+        let mut months: [String; 12] = Default::default();
+        for (i, month) in months.iter_mut().enumerate() {
+            *month = en::get_month_abbr(i as u8, true, None).unwrap();
+        }
+        months[8] = "Sept.".to_string();
+        ieee.locale.month_abbreviations = Some(months);
+        assert_eq!(format(&ieee, yaml), "“Annual report,” ACME, Sept. 14, 2020.");
+    }
+
+    #[test]
+    fn preprint_label() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            serial-number: 2003.13722
+            url: https://arxiv.org/abs/2003.13722
+            parent:
+                type: Repository
+                title: arXiv
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.preprint_label = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” arXiv: 2003.13722, 2020. \
+             [Preprint]. [Online]. Available: https://arxiv.org/abs/2003.13722"
+        );
+    }
+
+    #[test]
+    fn strict_2018() {
+        let yaml = r#"
+        collab:
+            type: Misc
+            title: A large collaboration
+            author: ["A, A", "B, B", "C, C", "D, D", "E, E", "F, F", "G, G"]
+            date: 2020
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "A. A, B. B, et al., “A large collaboration,” 2020."
+        );
+        assert_eq!(
+            format(&Ieee::strict_2018(), yaml),
+            "A. A et al., “A large collaboration,” 2020."
+        );
+
+        let six = yaml.replace(", \"G, G\"", "");
+        assert_eq!(
+            format(&Ieee::strict_2018(), &six),
+            "A. A, B. B, C. C, D. D, E. E, and F. F, “A large collaboration,” 2020."
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        let article = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            parent:
+                type: Periodical
+                title: Physical Review B
+        "#;
+
+        let misc = r#"
+        graphs:
+            type: Misc
+            title: On graphs
+            author: Smith, John
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.locale.quotes = ("\"".into(), "\"".into());
+        assert_eq!(
+            format(&ieee, article),
+            "T. D. Doan, \"Kinetics of excitations,\" Physical Rev. B, 2020."
+        );
+        assert_eq!(format(&ieee, misc), "J. Smith, \"On graphs.\"");
+
+        ieee.locale.quotes = ("«".into(), "»".into());
+        assert_eq!(
+            format(&ieee, article),
+            "T. D. Doan, «Kinetics of excitations,» Physical Rev. B, 2020."
+        );
+        assert_eq!(format(&ieee, misc), "J. Smith, «On graphs.»");
+    }
+
+    #[test]
+    fn subtitlers() {
+        let yaml = r#"
+        stalker:
+            type: Video
+            title: Stalker
+            date: 1979
+            affiliated:
+                - role: Director
+                  names: Tarkovsky, Andrei
+                - role: Subtitler
+                  names: Glenny, Michael
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "A. Tarkovsky, Director. Stalker, (1979), subtitled by M. Glenny."
+        );
+    }
+
+    #[test]
+    fn anonymous() {
+        let yaml = r#"
+        cloud:
+            type: Book
+            title: The cloud of unknowing
+            date: 1922
+            anonymous: true
+        "#;
+
+        assert_eq!(format(&Ieee::new(), yaml), "Anon., The Cloud of Unknowing, 1922.");
+
+        let unknown = yaml.replace("anonymous: true", "");
+        assert_eq!(format(&Ieee::new(), &unknown), "The Cloud of Unknowing, 1922.");
+    }
+
+    #[test]
+    fn web_site_editors() {
+        let yaml = r#"
+        essay:
+            type: Article
+            title: The ethics of open data
+            author: Doe, Jane
+            date: 2021
+            parent:
+                type: Web
+                title: Public Philosophy Review
+                editor: ["Smith, John", "Lee, Kim"]
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "J. Doe, “The ethics of open data,” Public Philosophy Review, J. Smith \
+             and K. Lee, Eds."
+        );
+    }
+
+    #[test]
+    fn access_date_only() {
+        let yaml = r#"
+        rust:
+            type: Web
+            title: Rust programming language
+            publisher: Rust Foundation
+            url:
+                value: https://www.rust-lang.org/
+                date: 2021-03-02
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "“Rust programming language,” Rust Foundation. https://www.rust-lang.org/ \
+             (accessed: Mar. 2, 2021)."
+        );
+
+        let unpublished = yaml.replace("publisher: Rust Foundation", "");
+        assert_eq!(
+            format(&Ieee::new(), &unpublished),
+            "“Rust programming language.” https://www.rust-lang.org/ (accessed: Mar. \
+             2, 2021)."
+        );
+    }
+
+    #[test]
+    fn affiliations() {
+        let yaml = r#"
+        survey:
+            type: Misc
+            title: A survey of sensor networks
+            author:
+                - name: Doe
+                  given-name: Jane
+                  affiliation: Univ. of Oxford
+                - name: Roe
+                  given-name: Richard
+                  affiliation: MIT
+            date: 2020
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe and R. Roe, “A survey of sensor networks,” 2020."
+        );
+
+        ieee.affiliations = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe (Univ. of Oxford) and R. Roe (MIT), “A survey of sensor \
+             networks,” 2020."
+        );
+
+        ieee.et_al_threshold = Some(2);
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, R. Roe, et al., “A survey of sensor networks,” 2020."
+        );
+    }
+
+    #[test]
+    fn initials() {
+        let yaml = r#"
+        graphs:
+            type: Misc
+            title: On graphs
+            author: Smith, John Adam
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(format(&ieee, yaml), "J. A. Smith, “On graphs.”");
+
+        ieee.initials = IeeeInitials::Unspaced;
+        assert_eq!(format(&ieee, yaml), "J.A. Smith, “On graphs.”");
+
+        ieee.initials = IeeeInitials::NoPeriods;
+        assert_eq!(format(&ieee, yaml), "JA Smith, “On graphs.”");
+    }
+
+    #[test]
+    fn url_delimiters() {
+        let yaml = r#"
+        rust:
+            type: Web
+            title: Rust programming language
+            url:
+                value: https://www.rust-lang.org/
+                date: 2021-03-02
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut ieee = Ieee::new();
+        ieee.url_delimiters = Some(('<', '>'));
+
+        let reference = ieee.reference(&Record::from_entry(&entries[0]));
+        let display = reference.display;
+        assert_eq!(
+            display.value,
+            "“Rust programming language.” <https://www.rust-lang.org/> (accessed: \
+             Mar. 2, 2021)."
+        );
+
+        let start = display.value.find('<').unwrap() + 1;
+        let end = display.value.find('>').unwrap();
+        assert_eq!(
+            display.formatting,
+            vec![(
+                start..end,
+                Formatting::Link("https://www.rust-lang.org/".to_string())
+            )]
+        );
+
+        let bare = yaml.replace("date: 2021-03-02", "");
+        assert!(format(&ieee, &bare).ends_with(" <https://www.rust-lang.org/>"));
+    }
+
+    #[test]
+    fn editor_counts() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: Neural networks
+            author: Doe, Jane
+            date: 2001
+            parent:
+                type: Book
+                title: Handbook of Learning
+                editor: ["Smith, John", "Lee, Kim", "Roe, Richard"]
+        "#;
+
+        let editors = |names: &str| {
+            let yaml =
+                yaml.replace(r#""Smith, John", "Lee, Kim", "Roe, Richard""#, names);
+            let res = format(&Ieee::new(), &yaml);
+            let start = res.find("Learn., ").unwrap() + 8;
+            res[start..].to_string()
+        };
+
+        assert_eq!(editors(r#""Smith, John""#), "J. Smith, Ed., 2001.");
+        assert_eq!(
+            editors(r#""Smith, John", "Lee, Kim""#),
+            "J. Smith and K. Lee, Eds., 2001."
+        );
+        assert_eq!(
+            editors(r#""Smith, John", "Lee, Kim", "Roe, Richard""#),
+            "J. Smith, K. Lee, and R. Roe, Eds., 2001."
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            serial-number: 3
+            parent:
+                type: Chapter
+                serial-number: Part II
+                parent:
+                    type: Book
+                    title: War and Peace
+                    author: Tolstoy, Leo
+                    date: 1869
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "L. Tolstoy, War and Peace, 1869, Part II, ch. 3."
+        );
+    }
+
+    #[test]
+    fn status() {
+        let manuscript = r#"
+        draft:
+            type: Manuscript
+            title: Scalable graph partitioning
+            author: Doe, Jane
+            date: 2021
+            status: under review
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), manuscript),
+            "J. Doe, “Scalable graph partitioning,” under review."
+        );
+        assert_eq!(
+            format(&Ieee::new(), &manuscript.replace("status: under review", "")),
+            "J. Doe, “Scalable graph partitioning,” unpublished."
+        );
+
+        let article = r#"
+        accepted:
+            type: Article
+            title: Scalable graph partitioning
+            author: Doe, Jane
+            date: 2021
+            status: accepted
+            parent:
+                type: Periodical
+                title: Journal of Graph Theory
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), article),
+            "J. Doe, “Scalable graph partitioning,” J. Graph Theory, accepted."
+        );
+    }
+
+    #[test]
+    fn editors_before_container() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: Neural networks
+            author: Doe, Jane
+            parent:
+                type: Book
+                title: Handbook of learning
+                editor: ["Smith, John", "Lee, Kim"]
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith and K. Lee, Eds., \
+             ACME, 2001."
+        );
+
+        ieee.editors_before_container = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Neural networks,” in J. Smith and K. Lee (Eds.), Handbook Learn., \
+             ACME, 2001."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace(", \"Lee, Kim\"", "")),
+            "J. Doe, “Neural networks,” in J. Smith (Ed.), Handbook Learn., ACME, 2001."
+        );
+    }
+
+    #[test]
+    fn page_totals() {
+        let yaml = r#"
+        book:
+            type: Book
+            title: Pattern recognition
+            author: Bishop, Christopher
+            publisher: Springer
+            date: 2006
+            page-total: 738
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "C. Bishop, Pattern Recognition, Springer, 2006."
+        );
+
+        ieee.page_totals = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "C. Bishop, Pattern Recognition, Springer, 2006, 738 pp."
+        );
+
+        let front = yaml.replace(
+            "page-total: 738",
+            "page-total: 738\n            front-matter-pages: xx",
+        );
+        assert_eq!(
+            format(&ieee, &front),
+            "C. Bishop, Pattern Recognition, Springer, 2006, xx + 738 pp."
+        );
+    }
+
+    #[test]
+    fn with_locale() {
+        let report = r#"
+        report:
+            type: Report
+            title: Annual report
+            author: ["Doe, Jane", "Roe, Richard"]
+            organization: ACME
+            date: 2020-03-14
+        "#;
+
+        let book = r#"
+        zauberberg:
+            type: Book
+            title: The Magic Mountain
+            author: Mann, Thomas
+            publisher: Knopf
+            location: New York
+            language: en
+            date: 1927
+        "#;
+
+        let mut english = Ieee::new();
+        english.initials = IeeeInitials::NoPeriods;
+        english
+            .locale
+            .language_annotations
+            .insert("en".into(), "English".into());
+
+        let german = english.with_locale(&"de-DE".parse().unwrap());
+        assert_eq!(german.initials, english.initials);
+        assert_eq!(
+            format(&english, report),
+            "J Doe and R Roe, “Annual report,” ACME, Mar. 14, 2020."
+        );
+        assert_eq!(
+            format(&german, report),
+            "J Doe and R Roe, „Annual report,“ ACME, März 14, 2020."
+        );
+        assert_eq!(
+            format(&english, book),
+            "T Mann, The Magic Mountain, New York: Knopf (English), 1927."
+        );
+        assert_eq!(
+            format(&german, book),
+            "T Mann, The Magic Mountain, New York: Knopf (auf Englisch), 1927."
+        );
+    }
+
+    #[test]
+    fn normalize_locations() {
+        let yaml = r#"
+        paper:
+            type: Article
+            title: Fast routing
+            author: Doe, Jane
+            parent:
+                type: Proceedings
+                title: Proc. Int. Conf. Networks
+                location: Boston, Massachusetts
+                date: 2019
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Boston, Massachusetts, 2019."
+        );
+
+        ieee.normalize_locations = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Boston, MA, USA, 2019."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("Massachusetts", "Massachusetts, United States")),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Boston, MA, USA, 2019."
+        );
+        assert_eq!(
+            format(
+                &ieee,
+                &yaml.replace("Boston, Massachusetts", "London, United Kingdom")
+            ),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, London, U.K., 2019."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("Boston, Massachusetts", "Tbilisi, Georgia")),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Tbilisi, Georgia, 2019."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("Boston, Massachusetts", "Stuttgart, DE")),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Stuttgart, DE, 2019."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("Boston, Massachusetts", "Atlanta, GA, USA")),
+            "J. Doe, “Fast routing,” in Proc. Int. Conf. Networks, Atlanta, GA, USA, 2019."
+        );
+    }
+
+    #[test]
+    fn container_casing() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: Grasping
+            author: Doe, Jane
+            parent:
+                type: Book
+                title: Handbook of robot learning
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.abbreviate_journals = false;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Grasping,” in Handbook of Robot Learning, ACME, 2001."
+        );
+
+        ieee.book_casing = IeeeCasing::Sentence;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Grasping,” in Handbook of robot learning, ACME, 2001."
+        );
+
+        let article = r#"
+        article:
+            type: Article
+            title: Grasping
+            author: Doe, Jane
+            date: 2001
+            parent:
+                type: Periodical
+                title: Journal of robot learning
+        "#;
+        assert_eq!(
+            format(&ieee, article),
+            "J. Doe, “Grasping,” Journal of Robot Learning, 2001."
+        );
+    }
+
+    #[test]
+    fn diagnostics() {
+        let yaml = r#"
+        article:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            language: xy
+            doi: 10.1103/PhysRevB.102.165126
+            parent:
+                type: Periodical
+                title: Physical Review B
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut events = vec![];
+        let reference = Ieee::new()
+            .format_with_diagnostics(&Record::from_entry(&entries[0]), &mut events);
+
+        assert_eq!(
+            reference.display.value,
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, \
+             doi: 10.1103/PhysRevB.102.165126."
+        );
+        assert_eq!(
+            events,
+            vec![
+                FormatEvent::SkippedLanguage("xy".to_string()),
+                FormatEvent::UsedDoi,
+                FormatEvent::MissingDate,
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostics_skipped_language_once() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: On the shore
+            author: Doe, Jane
+            language: xy
+            date: 2001
+            parent:
+                type: Book
+                title: Collected stories
+                publisher: Penguin
+                location: London
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut events = vec![];
+        let reference = Ieee::new()
+            .format_with_diagnostics(&Record::from_entry(&entries[0]), &mut events);
+
+        assert_eq!(
+            reference.display.value,
+            "J. Doe, “On the shore,” in Collected Stories, London: Penguin, 2001."
+        );
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, FormatEvent::SkippedLanguage(_)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn bidi_isolates() {
+        let yaml = r#"
+        page:
+            type: Web
+            title: الصفحة الرئيسية
+            author: Doe, Jane
+            language: ar
+            url: https://example.org/ar
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “الصفحة الرئيسية.” https://example.org/ar"
+        );
+
+        ieee.bidi_isolates = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "\u{2066}J. Doe\u{2069}, “الصفحة الرئيسية.” \u{2066}https://example.org/ar\u{2069}"
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("language: ar", "language: en")),
+            "J. Doe, “الصفحة الرئيسية.” https://example.org/ar"
+        );
+    }
+
+    #[test]
+    fn video_roles() {
+        let yaml = r#"
+        documentary:
+            type: Video
+            title: Cosmos
+            date: 1980
+            affiliated:
+                - role: Presenter
+                  names: Sagan, Carl
+                - role: Director
+                  names: ["Malone, Adrian", "Andorfer, Gregory"]
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "A. Malone and G. Andorfer, Directors. Cosmos, (1980)."
+        );
+
+        ieee.video_roles.insert(0, PersonRole::Presenter);
+        assert_eq!(format(&ieee, yaml), "C. Sagan, Presenter. Cosmos, (1980).");
+    }
+
+    #[test]
+    fn translators() {
+        let yaml = r#"
+        sutra:
+            type: Book
+            title: The diamond sutra
+            publisher: Shambhala
+            date: 2001
+            affiliated:
+                - role: Translator
+                  names: Lee, Kim
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "K. Lee, Trans., The Diamond Sutra, Shambhala, 2001."
+        );
+        assert_eq!(
+            format(
+                &Ieee::new(),
+                &yaml.replace("publisher", "author: Doe, Jane\n            publisher")
+            ),
+            "J. Doe, The Diamond Sutra, Shambhala, 2001."
+        );
+    }
+
+    #[test]
+    fn year_format() {
+        let yaml = r#"
+        book:
+            type: Book
+            title: Modern physics
+            author: Doe, Jane
+            publisher: ACME
+            date: 2019
+        "#;
+        let ancient = yaml.replace("date: 2019", "date: -499");
+
+        let mut ieee = Ieee::new();
+        assert_eq!(format(&ieee, yaml), "J. Doe, Modern Physics, ACME, 2019.");
+        assert_eq!(format(&ieee, &ancient), "J. Doe, Modern Physics, ACME, 500 BCE.");
+
+        ieee.year_format = IeeeYearFormat::TwoDigit;
+        assert_eq!(format(&ieee, yaml), "J. Doe, Modern Physics, ACME, 19.");
+        assert_eq!(format(&ieee, &ancient), "J. Doe, Modern Physics, ACME, 500 BCE.");
+
+        ieee.year_format = IeeeYearFormat::Era;
+        assert_eq!(format(&ieee, yaml), "J. Doe, Modern Physics, ACME, 2019 CE.");
+        assert_eq!(format(&ieee, &ancient), "J. Doe, Modern Physics, ACME, 500 BCE.");
+
+        let video = r#"
+        film:
+            type: Video
+            title: The ancient world
+            affiliated:
+                - role: Director
+                  names: Doe, Jane
+            date: -499
+        "#;
+        assert_eq!(
+            format(&Ieee::new(), video),
+            "J. Doe, Director. The Ancient World, (500 BCE)."
+        );
+
+        let repository = r#"
+        tablets:
+            type: Repository
+            title: Clay tablets
+            author: Doe, Jane
+            date: -499
+        "#;
+        assert_eq!(format(&Ieee::new(), repository), "J. Doe, Clay Tablets, (500 BCE).");
+    }
+
+    #[test]
+    fn seasons() {
+        let yaml = r#"
+        essay:
+            type: Article
+            title: On gardens
+            author: Doe, Jane
+            page-range: 12-18
+            parent:
+                type: Periodical
+                title: Landscape Review
+                volume: 7
+                season: Spring
+                date: 2020
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "J. Doe, “On gardens,” Landscape Rev., vol. 7, Spring 2020, pp. 12–18."
+        );
+        assert_eq!(
+            format(&Ieee::new(), &yaml.replace("season: Spring", "issue: 2")),
+            "J. Doe, “On gardens,” Landscape Rev., vol. 7, no. 2, pp. 12–18, 2020."
+        );
+    }
+
+    #[test]
+    fn doi_period() {
+        let yaml = r#"
+        article:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            doi: 10.1103/PhysRevB.102.165126
+            parent:
+                type: Periodical
+                title: Physical Review B
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020, \
+             doi: 10.1103/PhysRevB.102.165126."
+        );
+
+        ieee.doi_period = IeeeDoiPeriod::Spaced;
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020, \
+             doi: 10.1103/PhysRevB.102.165126 ."
+        );
+
+        ieee.doi_period = IeeeDoiPeriod::Omitted;
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020, \
+             doi: 10.1103/PhysRevB.102.165126"
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("doi: 10.1103/PhysRevB.102.165126", "")),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020."
+        );
+        assert_eq!(
+            format(
+                &ieee,
+                &yaml.replace(
+                    "date: 2020",
+                    "date: 2020\n            url: https://example.org/kinetics"
+                )
+            ),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020, \
+             doi: 10.1103/PhysRevB.102.165126. [Online]. Available: \
+             https://example.org/kinetics"
+        );
+    }
+
+    #[test]
+    fn edition_before_volume() {
+        let yaml = r#"
+        art:
+            type: Book
+            title: The art of computer programming
+            author: Knuth, Donald E.
+            volume: 3
+            edition: 2
+            publisher: Addison-Wesley
+            date: 1998
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "D. E. Knuth, The Art of Computer Programming, vol. 3, 2nd ed., \
+             Addison-Wesley, 1998."
+        );
+
+        ieee.edition_before_volume = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "D. E. Knuth, The Art of Computer Programming, 2nd ed., vol. 3, \
+             Addison-Wesley, 1998."
+        );
+    }
+
+    #[test]
+    fn access_note() {
+        let yaml = r#"
+        report:
+            type: Report
+            title: Market outlook
+            organization: ACME
+            date: 2021
+            access-note: registration required
+            url: https://example.org/outlook
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "“Market outlook,” ACME, 2021. [Online]. Available: \
+             https://example.org/outlook (registration required)"
+        );
+        assert_eq!(
+            format(&Ieee::new(), &yaml.replace("url: https://example.org/outlook", "")),
+            "“Market outlook,” ACME, 2021."
+        );
+
+        let web = r#"
+        outlook:
+            type: Web
+            title: Market outlook
+            organization: ACME
+            access-note: registration required
+            url:
+                value: https://example.org/outlook
+                date: 2020-01-01
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), web),
+            "“Market outlook,” ACME. https://example.org/outlook \
+             (accessed: Jan. 1, 2020; registration required)."
+        );
+        assert_eq!(
+            format(&Ieee::new(), &web.replace("date: 2020-01-01", "")),
+            "“Market outlook,” ACME. https://example.org/outlook (registration required)"
+        );
+    }
+
+    #[test]
+    fn identifiers() {
+        let yaml = r#"
+        report:
+            type: Report
+            title: Demand forecasting practices
+            author: Roundy, R.
+            organization: Cornell University
+            date: 2001
+            handle: 1813/9174
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "R. Roundy, “Demand forecasting practices,” Cornell University, 2001, \
+             hdl: 1813/9174."
+        );
+        assert_eq!(
+            format(&ieee, &yaml.replace("1813/9174", "hdl:1813/9174")),
+            format(&ieee, yaml)
+        );
+
+        ieee.identifier_urls = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "R. Roundy, “Demand forecasting practices,” Cornell University, 2001, \
+             https://hdl.handle.net/1813/9174."
+        );
+
+        let urn = yaml.replace("handle: 1813/9174", "urn: urn:nbn:de:101:1-201609052530");
+        assert_eq!(
+            format(&ieee, &urn),
+            "R. Roundy, “Demand forecasting practices,” Cornell University, 2001, \
+             urn: nbn:de:101:1-201609052530."
+        );
+    }
+
+    #[test]
+    fn all_authors() {
+        let yaml = r#"
+        collaboration:
+            type: Article
+            title: Observation of a new boson
+            author: ["Aad, G.", "Abajyan, T.", "Abbott, B.", "Abdallah, J.", "Abdel Khalek, S.", "Abdelalim, A. A."]
+            all-authors: true
+            date: 2012
+        other:
+            type: Article
+            title: Observation of a new boson
+            author: ["Aad, G.", "Abajyan, T.", "Abbott, B.", "Abdallah, J.", "Abdel Khalek, S.", "Abdelalim, A. A."]
+            date: 2012
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let bib = db.bibliography(&Ieee::new(), None);
+
+        assert_eq!(
+            bib[0].display.value,
+            "G. Aad, T. Abajyan, B. Abbott, J. Abdallah, S. Abdel Khalek, and \
+             A. A. Abdelalim, “Observation of a new boson,” 2012."
+        );
+        assert_eq!(
+            bib[1].display.value,
+            "G. Aad, T. Abajyan, et al., “Observation of a new boson,” 2012."
+        );
+    }
+
+    #[test]
+    fn deposited_date() {
+        let yaml = r#"
+        article:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            deposited-date: 2021-03-17
+            doi: 10.1103/PhysRevB.102.165126
+            parent:
+                type: Periodical
+                title: Physical Review B
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, Mar. 17, 2021, \
+             doi: 10.1103/PhysRevB.102.165126."
+        );
+        assert_eq!(
+            format(
+                &Ieee::new(),
+                &yaml.replace(
+                    "title: Physical Review B",
+                    "title: Physical Review B\n                date: 2020"
+                )
+            ),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020, \
+             doi: 10.1103/PhysRevB.102.165126."
+        );
+    }
+
+    #[test]
+    fn initials_separator() {
+        let yaml = r#"
+        book:
+            type: Book
+            title: Principles of optics
+            author: ["Born, Max", "Wolf, Emil Alfred", "van Dam, Jan"]
+            publisher: Pergamon
+            date: 1959
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.initials_separator = '\u{a0}';
+        assert_eq!(
+            format(&ieee, yaml),
+            "M.\u{a0}Born, E. A.\u{a0}Wolf, and J.\u{a0}van Dam, Principles of Optics, \
+             Pergamon, 1959."
+        );
+
+        ieee.initials = IeeeInitials::NoPeriods;
+        assert_eq!(
+            format(&ieee, yaml),
+            "M\u{a0}Born, EA\u{a0}Wolf, and J\u{a0}van Dam, Principles of Optics, \
+             Pergamon, 1959."
+        );
+    }
+
+    #[test]
+    fn classify() {
+        let yaml = r#"
+        attention:
+            type: Article
+            title: Attention Is All You Need
+            author: ["Vaswani, Ashish", "Shazeer, Noam"]
+            date: 2017
+            serial-number: "1706.03762"
+            parent:
+                type: Repository
+                title: arXiv
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(Ieee::new().classify(&entries[0]), IeeeAddonBranch::Preprint);
+    }
+
+    #[test]
+    fn language_annotations() {
+        let yaml = r#"
+        zauberberg:
+            type: Book
+            title: Der Zauberberg
+            author: Mann, Thomas
+            publisher: S. Fischer
+            location: Berlin
+            language: de
+            date: 1924
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. Mann, Der Zauberberg, Berlin: S. Fischer (in German), 1924."
+        );
+
+        ieee.locale
+            .language_annotations
+            .insert("de".into(), "auf Deutsch".into());
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. Mann, Der Zauberberg, Berlin: S. Fischer (auf Deutsch), 1924."
+        );
+    }
+
+    #[test]
+    fn repository_access_date() {
+        let yaml = r#"
+        typst:
+            type: Repository
+            title: Typst
+            author: Mädje, Laurenz
+            serial-number: "0.2.0"
+            publisher: GitHub
+            date: 2023
+            url:
+                value: https://github.com/typst/typst
+                date: 2023-05-02
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "L. Mädje, Typst, (version 0.2.0), GitHub. Accessed: May 2, 2023. \
+             [Online]. Available: https://github.com/typst/typst"
+        );
+
+        ieee.access_dates = IeeeAccessDateVisibility::Never;
+        assert_eq!(
+            format(&ieee, yaml),
+            "L. Mädje, Typst, (version 0.2.0), GitHub. [Online]. Available: \
+             https://github.com/typst/typst"
+        );
+    }
+
+    #[test]
+    fn collected_works() {
+        let yaml = r#"
+        on-truth:
+            type: Anthos
+            title: On truth and lies in a nonmoral sense
+            author: Nietzsche, Friedrich
+            issue: 12
+            parent:
+                type: Anthology
+                title: Collected works
+                publisher: Stanford University Press
+                location: Stanford, CA
+                date: 1995
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "F. Nietzsche, “On truth and lies in a nonmoral sense,” no. 12 in \
+             Collected Works, Stanford, CA: Stanford University Press, 1995."
+        );
+    }
+
+    #[test]
+    fn commit() {
+        let yaml = r#"
+        typst:
+            type: Repository
+            title: Typst
+            author: Mädje, Laurenz
+            serial-number: "0.2.0"
+            commit: 3f9c2a1e8b7d4c6f5a0e9d8c7b6a5f4e3d2c1b0a
+            publisher: GitHub
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "L. Mädje, Typst, (version 0.2.0), commit 3f9c2a1, GitHub."
+        );
+
+        let checksum = yaml.replace("3f9c2a1e8b7d", "sha256:9f86d081884c7d65");
+        assert_eq!(
+            format(&ieee, &checksum),
+            "L. Mädje, Typst, (version 0.2.0), SHA-256: 9f86d08…, GitHub."
+        );
+
+        ieee.hash_length = Some(12);
+        assert_eq!(
+            format(&ieee, yaml),
+            "L. Mädje, Typst, (version 0.2.0), commit 3f9c2a1e8b7d, GitHub."
+        );
+    }
+
+    #[test]
+    fn et_al_separators() {
+        let yaml = r#"
+        boson:
+            type: Article
+            title: Observation of a new boson
+            author: ["Aad, G.", "Abajyan, T.", "Abbott, B.", "Abdallah, J.", "Abdel Khalek, S.", "Abdelalim, A. A."]
+            date: 2012-06
+            url: https://example.org/talk
+            parent:
+                type: Conference
+                title: International Conference on High Energy Physics
+                location: Melbourne, Australia
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "G. Aad, T. Abajyan, et al. (Jun. 2012). Observation of a new boson. \
+             Presented at Int. Conf. High Energy Phys., Melbourne, Australia. \
+             [Online]. Available: https://example.org/talk"
+        );
+
+        let article = yaml
+            .replace("url: https://example.org/talk", "")
+            .replace(
+                "International Conference on High Energy Physics",
+                "Physics Letters B",
+            )
+            .replace("Conference", "Periodical");
+        assert_eq!(
+            format(&Ieee::new(), &article),
+            "G. Aad, T. Abajyan, et al., “Observation of a new boson,” Phys. Lett. \
+             B, Jun. 2012."
+        );
+    }
+
+    #[test]
+    fn translated_container() {
+        let yaml = r#"
+        sprache:
+            type: Chapter
+            title: Die Sprache
+            author: Heidegger, Martin
+            date: 1959
+            parent:
+                type: Book
+                title:
+                    value: Unterwegs zur Sprache
+                    translation: On the way to language
+                publisher: Neske
+                location: Pfullingen
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let canonical = get_canonical_parent(&entries[0]).unwrap();
+        let res =
+            Ieee::new().get_title_element(&entries[0], canonical, false, &mut vec![]);
+
+        assert_eq!(
+            res.value,
+            "“Die sprache,” in Unterwegs Zur Sprache [On the Way to Language]"
+        );
+        assert_eq!(res.formatting.len(), 1);
+        assert_eq!(&res.value[res.formatting[0].0.clone()], "Unterwegs Zur Sprache");
+    }
+
+    #[test]
+    fn medium() {
+        let yaml = r#"
+        stalker:
+            type: Video
+            title: Stalker
+            date: 1979
+            medium: DVD
+            affiliated:
+                - role: Director
+                  names: Tarkovsky, Andrei
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "A. Tarkovsky, Director. Stalker, (1979). [DVD]."
+        );
+
+        let online = yaml.replace("medium: DVD", "url: https://example.org/stalker");
+        assert_eq!(
+            format(&Ieee::new(), &online),
+            "A. Tarkovsky, Director. Stalker, (1979). [Online Video]. Available: \
+             https://example.org/stalker"
+        );
+
+        let both =
+            online.replace("date: 1979", "date: 1979\n            medium: Blu-ray");
+        assert_eq!(
+            format(&Ieee::new(), &both),
+            "A. Tarkovsky, Director. Stalker, (1979). [Blu-ray]. Available: \
+             https://example.org/stalker"
+        );
+    }
+
+    #[test]
+    fn series_volume() {
+        let yaml = r#"
+        weyl:
+            type: Anthos
+            title: Symmetry and relativity
+            author: Weyl, Hermann
+            parent:
+                type: Anthology
+                title: Collected papers
+                publisher: Springer
+                date: 1968
+                parent:
+                    type: Anthology
+                    title: Classics in mathematics
+                    volume: 3
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "H. Weyl, “Symmetry and relativity,” in Collected Papers (Classics in \
+             Mathematics, vol. 3), Springer, 1968."
+        );
+
+        let numbered = yaml.replace("volume: 3", "issue: 12");
+        assert_eq!(
+            format(&Ieee::new(), &numbered),
+            "H. Weyl, “Symmetry and relativity,” in Collected Papers (Classics in \
+             Mathematics, no. 12), Springer, 1968."
+        );
+    }
+
+    #[test]
+    fn presented_at() {
+        let yaml = r#"
+        talk:
+            type: Article
+            title: Observation of a new boson
+            author: Aad, G.
+            date: 2012-06
+            url: https://example.org/talk
+            parent:
+                type: Conference
+                title: International Conference on High Energy Physics
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.presented_at = "Paper presented at ".to_string();
+        assert_eq!(
+            format(&ieee, yaml),
+            "G. Aad. (Jun. 2012). Observation of a new boson. Paper presented at \
+             Int. Conf. High Energy Phys. [Online]. Available: https://example.org/talk"
+        );
+
+        ieee.presented_at = String::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "G. Aad. (Jun. 2012). Observation of a new boson. Int. Conf. High Energy \
+             Phys. [Online]. Available: https://example.org/talk"
+        );
+    }
+
+    #[test]
+    fn arxiv_with_doi() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            doi: 10.1103/PhysRevB.102.165126
+            arxiv: "arXiv:2004.01234"
+            parent:
+                type: Periodical
+                title: Physical Review B
+                volume: 102
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, vol. 102, 2020, \
+             doi: 10.1103/PhysRevB.102.165126."
+        );
+
+        ieee.arxiv_with_doi = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, vol. 102, 2020, \
+             doi: 10.1103/PhysRevB.102.165126, arXiv: 2004.01234."
+        );
+
+        let preprint = yaml.replace("doi: 10.1103/PhysRevB.102.165126", "");
+        assert_eq!(
+            format(&Ieee::new(), &preprint),
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, vol. 102, 2020, \
+             arXiv: 2004.01234."
+        );
+    }
+
+    #[test]
+    fn editor_et_al() {
+        let editors = r#"["Adams, Ann", "Baker, Ben", "Clark, Cid", "Davis, Dan", "Evans, Eve", "Fox, Fay", "Gray, Gus"]"#;
+        let proceedings = format!(
+            r#"
+        paper:
+            type: Article
+            title: On graphs
+            author: Smith, John
+            parent:
+                type: Proceedings
+                title: Graph theory
+                editor: {}
+                date: 2020
+        "#,
+            editors
+        );
+        let chapter = format!(
+            r#"
+        chapter:
+            type: Chapter
+            title: On graphs
+            author: Smith, John
+            parent:
+                type: Book
+                title: Graph theory
+                editor: {}
+                date: 2020
+        "#,
+            editors
+        );
+
+        let mut ieee = Ieee::new();
+        let eds = "A. Adams, B. Baker, et al., Eds.";
+        assert!(format(&ieee, &proceedings).contains(eds));
+        assert!(format(&ieee, &chapter).contains(eds));
+
+        ieee.editor_et_al_threshold = None;
+        let eds = "A. Adams, B. Baker, C. Clark, D. Davis, E. Evans, F. Fox, and G. \
+                   Gray, Eds.";
+        assert!(format(&ieee, &proceedings).contains(eds));
+        assert!(format(&ieee, &chapter).contains(eds));
+    }
+
+    #[test]
+    fn acronyms() {
+        let yaml = r#"
+        report:
+            type: Report
+            title: Annual report
+            author:
+                - name: National Institutes of Health
+                  acronym: NIH
+                - name: Doe
+                  given-name: Jane
+                  acronym: JD
+            organization: ACME
+            date: 2020
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "National Institutes of Health (NIH) and J. Doe, “Annual report,” ACME, 2020."
+        );
+    }
+
+    #[test]
+    fn journal_resolver() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            parent:
+                type: Periodical
+                title: Physical Review B
+                volume: 102
+        "#;
+
+        let mut ieee = Ieee::new();
+        ieee.journal_resolver = Some(IeeeJournalResolver::new(|title| {
+            if title == "Physical Review B" {
+                Some("Phys. Rev. B".to_string())
+            } else {
+                None
+            }
+        }));
+
+        assert_eq!(
+            format(&ieee, yaml),
+            "T. D. Doan, “Kinetics of excitations,” Phys. Rev. B, vol. 102, 2020."
+        );
+
+        let other = yaml.replace("Physical Review B", "Journal of Physics");
+        assert_eq!(
+            format(&ieee, &other),
+            "T. D. Doan, “Kinetics of excitations,” J. Phys., vol. 102, 2020."
+        );
+    }
+
+    #[test]
+    fn long_director_list() {
+        let yaml = r#"
+        pilot:
+            type: Video
+            title: Pilot
+            date: 2008
+            affiliated:
+                - role: Director
+                  names: ["A, Anna", "B, Bert", "C, Carl", "D, Dora", "E, Emil", "F, Fritz", "G, Gert"]
+            volume: 1
+            issue: 3
+            parent:
+                type: Video
+                title: Breaking Bad
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "A. A (Director), B. B (Director), et al. Pilot, S1E3, (2008)."
+        );
+
+        let all = yaml.replace("date: 2008", "date: 2008\n            all-authors: true");
+        assert!(format(&Ieee::new(), &all).contains("G. G (Director)"));
+
+        let film = r#"
+        heat:
+            type: Video
+            title: Heat
+            date: 1995
+            affiliated:
+                - role: executive-producer
+                  names: ["A, Anna", "B, Bert", "C, Carl", "D, Dora", "E, Emil", "F, Fritz", "G, Gert"]
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), film),
+            "A. A, B. B, et al., Executive Prods. Heat, (1995)."
+        );
+
+        let all = film.replace("date: 1995", "date: 1995\n            all-authors: true");
+        assert_eq!(
+            format(&Ieee::new(), &all),
+            "A. A, B. B, C. C, D. D, E. E, F. F, and G. G, Executive Prods. Heat, (1995)."
+        );
+    }
+
+    #[test]
+    fn episode_locator() {
+        let yaml = r#"
+        pilot:
+            type: Video
+            title: Pilot
+            date: 2008
+            affiliated:
+                - role: Director
+                  names: ["Gilligan, Vince"]
+            volume: 1
+            issue: 3
+            parent:
+                type: Video
+                title: Breaking Bad
+        "#;
+
+        let expected = "V. Gilligan (Director). Pilot, S1E3, (2008).";
+        assert_eq!(format(&Ieee::new(), yaml), expected);
+
+        // Untitled scenes are located by the episode they belong to.
+        let scene = r#"
+        scene:
+            type: Scene
+            serial-number: 2
+            parent:
+                type: Video
+                title: Pilot
+                date: 2008
+                affiliated:
+                    - role: Director
+                      names: ["Gilligan, Vince"]
+                volume: 1
+                issue: 3
+                parent:
+                    type: Video
+                    title: Breaking Bad
+        "#;
+        assert_eq!(format(&Ieee::new(), scene), expected);
+    }
+
+    #[test]
+    fn no_italics() {
+        let yaml = r#"
+        big:
+            type: Article
+            title: A large collaboration
+            author: ["A, A", "B, B", "C, C", "D, D", "E, E", "F, F"]
+            date: 2020
+            parent:
+                type: Periodical
+                title: Nature
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let record = Record::from_entry(&entries[0]);
+
+        let mut ieee = Ieee::new();
+        ieee.italic_et_al = true;
+        let italic = ieee.reference(&record).display;
+        assert_eq!(italic.formatting.len(), 2);
+
+        ieee.italics = false;
+        let plain = ieee.reference(&record).display;
+        assert_eq!(plain.value, italic.value);
+        assert!(plain.formatting.iter().all(|(_, f)| *f != Formatting::Italic));
+    }
+
+    #[test]
+    fn editor_template() {
+        let volume = |editors: &str| {
+            format!(
+                r#"
+        handbook:
+            type: Anthology
+            title: Handbook of Learning
+            editor: [{}]
+            date: 2001
+        "#,
+                editors
+            )
+        };
+        let chapter = |editors: &str| {
+            format!(
+                r#"
+        chapter:
+            type: Chapter
+            title: Neural networks
+            author: Doe, Jane
+            parent:
+                type: Anthology
+                title: Handbook of Learning
+                editor: [{}]
+                date: 2001
+        "#,
+                editors
+            )
+        };
+
+        let one = r#""Smith, John""#;
+        let two = r#""Smith, John", "Lee, Kim""#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, &volume(one)),
+            "J. Smith, Ed., Handbook of Learning, 2001."
+        );
+        assert_eq!(
+            format(&ieee, &volume(two)),
+            "J. Smith and K. Lee, Eds., Handbook of Learning, 2001."
+        );
+        assert_eq!(
+            format(&ieee, &chapter(one)),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith, Ed., 2001."
+        );
+        assert_eq!(
+            format(&ieee, &chapter(two)),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith and K. Lee, \
+             Eds., 2001."
+        );
+
+        ieee.editor_template = "{names} ({ed})".to_string();
+        assert_eq!(
+            format(&ieee, &volume(one)),
+            "J. Smith (Ed.), Handbook of Learning, 2001."
+        );
+        assert_eq!(
+            format(&ieee, &volume(two)),
+            "J. Smith and K. Lee (Eds.), Handbook of Learning, 2001."
+        );
+        assert_eq!(
+            format(&ieee, &chapter(one)),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith (Ed.), 2001."
+        );
+        assert_eq!(
+            format(&ieee, &chapter(two)),
+            "J. Doe, “Neural networks,” in Handbook Learn., J. Smith and K. Lee \
+             (Eds.), 2001."
+        );
+    }
+
+    #[test]
+    fn archive_url() {
+        let yaml = r#"
+        page:
+            type: Web
+            title: Example Domain
+            author: Doe, Jane
+            date: 2019
+            url: { value: https://example.com/, date: 2020-01-01 }
+            archive-url: https://web.archive.org/web/20200101000000/https://example.com/
+        "#;
+        let book = yaml.replace("Web", "Book");
+
+        let ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Doe, “Example domain.” https://example.com/ (accessed: Jan. 1, \
+             2020). Archived at: \
+             https://web.archive.org/web/20200101000000/https://example.com/"
+        );
+        assert_eq!(
+            format(&ieee, &book),
+            "J. Doe, Example Domain, 2019. Accessed: Jan. 1, 2020. [Online]. \
+             Available: https://example.com/ Archived at: \
+             https://web.archive.org/web/20200101000000/https://example.com/"
+        );
+
+        // Only the snapshot is cited once the live URL is dead.
+        assert_eq!(
+            format(&ieee, &format!("{}    dead-url: true\n", yaml)),
+            "J. Doe, “Example domain.” \
+             https://web.archive.org/web/20200101000000/https://example.com/"
+        );
+    }
+
+    #[test]
+    fn cited_by() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            parent:
+                type: Periodical
+                title: Journal of Physics
+        "#;
+        let counted = format!("{}    cited-by: 42\n", yaml);
+
+        let mut ieee = Ieee::new();
+        let plain = "T. D. Doan, “Kinetics of excitations,” J. Phys., 2020.";
+        assert_eq!(format(&ieee, yaml), plain);
+        assert_eq!(format(&ieee, &counted), plain);
+
+        ieee.cited_by_counts = true;
+        assert_eq!(format(&ieee, yaml), plain);
+        assert_eq!(format(&ieee, &counted), format!("{} (cited by 42)", plain));
+    }
+
+    #[test]
+    fn locale_quotes() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+            parent:
+                type: Periodical
+                title: Journal of Physics
+        "#;
+
+        let misc = r#"
+        graphs:
+            type: Misc
+            title: On graphs
+            author: Smith, John
+        "#;
+
+        let ieee = Ieee::new();
+        let german = ieee.with_locale(&"de-DE".parse().unwrap());
+        assert_eq!(
+            format(&german, yaml),
+            "T. D. Doan, „Kinetics of excitations,“ J. Phys., 2020."
+        );
+        assert_eq!(format(&german, misc), "J. Smith, „On graphs.“");
+
+        let french = ieee.with_locale(&"fr".parse().unwrap());
+        assert_eq!(
+            format(&french, yaml),
+            "T. D. Doan, «\u{202F}Kinetics of excitations,\u{202F}» J. Phys., 2020."
+        );
+        assert_eq!(format(&french, misc), "J. Smith, «\u{202F}On graphs.\u{202F}»");
+
+        let english = french.with_locale(&"en-US".parse().unwrap());
+        assert_eq!(english.locale, ieee.locale);
+
+        // The quotation marks of the locale can be overridden.
+        let mut swiss = ieee.with_locale(&"de-CH".parse().unwrap());
+        assert_eq!(swiss.locale.quotes, ("«".into(), "»".into()));
+        swiss.locale.quotes = ("\"".into(), "\"".into());
+        assert_eq!(format(&swiss, misc), "J. Smith, \"On graphs.\"");
+    }
+
+    #[test]
+    fn see_also() {
+        let yaml = r#"
+        survey:
+            type: Article
+            title: A survey of graphs
+            author: Smith, John
+            date: 2010
+            see-also: [trees, cycles, uncited]
+        trees:
+            type: Article
+            title: On trees
+            author: Lee, Kim
+            date: 2008
+        cycles:
+            type: Article
+            title: On cycles
+            author: Roe, Richard
+            date: 2009
+        uncited:
+            type: Article
+            title: On paths
+            author: Doe, Jane
+            date: 2011
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut db = Database::new();
+        let mut numerical = crate::style::Numerical::new();
+        for entry in &entries[..3] {
+            db.citation(&mut numerical, &[crate::style::Citation::new(entry, None)]);
+        }
+
+        let mut ieee = Ieee::new();
+        let bib = db.bibliography(&ieee, None);
+        assert_eq!(bib[0].display.value, "J. Smith, “A survey of graphs,” 2010.");
+
+        ieee.see_also = true;
+        let bib = db.bibliography(&ieee, None);
+        assert_eq!(
+            bib[0].display.value,
+            "J. Smith, “A survey of graphs,” 2010. (see also [2], [3])"
+        );
+        assert_eq!(bib[1].display.value, "K. Lee, “On trees,” 2008.");
+    }
+
+    #[test]
+    fn conference_pages() {
+        let yaml = r#"
+        boson:
+            type: Article
+            title: Observation of a new boson
+            author: Aad, Georges
+            page-range: 12-18
+            parent:
+                type: Conference
+                title: International Conference on High Energy Physics
+                location: Melbourne, Australia
+                date: 2012-07
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "G. Aad, Observation of a new boson. Presented at Int. Conf. High Energy \
+             Phys., Melbourne, Australia, Jul. 2012, pp. 12–18."
+        );
+
+        // The paper number takes precedence over the pages.
+        let numbered = format!("{}    serial-number: TUA-3\n", yaml);
+        assert_eq!(
+            format(&Ieee::new(), &numbered),
+            "G. Aad, Observation of a new boson. Presented at Int. Conf. High Energy \
+             Phys., Melbourne, Australia, Jul. 2012, Paper TUA-3."
+        );
+    }
+
+    #[test]
+    fn lead_with_editors() {
+        let yaml = r#"
+        emma:
+            type: Book
+            title: Emma
+            author: Austen, Jane
+            editor: Smith, John
+            publisher: Penguin
+            location: London
+            date: 2003
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Austen, Emma, J. Smith, Ed., London: Penguin, 2003."
+        );
+
+        ieee.lead_with_editors = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Smith, Ed., Emma, by J. Austen, London: Penguin, 2003."
+        );
+
+        // Works without editors of their own still lead with the authors.
+        let authored = yaml.replace("            editor: Smith, John\n", "");
+        assert_eq!(format(&ieee, &authored), "J. Austen, Emma, London: Penguin, 2003.");
+    }
+
+    #[test]
+    fn doi_agencies() {
+        let yaml = r#"
+        data:
+            type: Repository
+            title: Survey of graphs dataset
+            author: Smith, John
+            date: 2019
+            publisher: Zenodo
+            doi: 10.5281/zenodo.3242074
+            registration-agency: DataCite
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Smith, Survey of Graphs Dataset, (2019), Zenodo."
+        );
+
+        ieee.doi_agencies = true;
+        assert_eq!(
+            format(&ieee, yaml),
+            "J. Smith, Survey of Graphs Dataset, (2019), Zenodo, \
+             doi: 10.5281/zenodo.3242074 [DataCite]."
+        );
+
+        // Repositories without an agency print no DOI, as before.
+        let unregistered = yaml.replace("registration-agency: DataCite", "");
+        assert_eq!(
+            format(&ieee, &unregistered),
+            "J. Smith, Survey of Graphs Dataset, (2019), Zenodo."
+        );
+    }
+
+    #[test]
+    fn all_entry_types() {
+        let types = [
+            Article,
+            Chapter,
+            Entry,
+            Anthos,
+            Report,
+            Thesis,
+            Web,
+            Scene,
+            Artwork,
+            Patent,
+            Case,
+            Newspaper,
+            Legislation,
+            Manuscript,
+            Tweet,
+            Misc,
+            Periodical,
+            Proceedings,
+            Book,
+            Blog,
+            Reference,
+            Conference,
+            Anthology,
+            Repository,
+            Thread,
+            Video,
+            Audio,
+            Exhibition,
+        ];
+
+        for &child in &types {
+            // Fails to compile when a type is added but not listed above.
+            match child {
+                Article | Chapter | Entry | Anthos | Report | Thesis | Web | Scene
+                | Artwork | Patent | Case | Newspaper | Legislation | Manuscript
+                | Tweet | Misc | Periodical | Proceedings | Book | Blog | Reference
+                | Conference | Anthology | Repository | Thread | Video | Audio
+                | Exhibition => {}
+            }
+
+            for parent in [None].iter().copied().chain(types.iter().copied().map(Some)) {
+                let mut yaml = format!(
+                    r#"
+        item:
+            type: {}
+            title: A title
+            author: Doe, Jane
+            date: 2020-05-04
+            serial-number: 3
+            page-range: 1-10
+            url: https://example.com/
+        "#,
+                    child
+                );
+                if let Some(parent) = parent {
+                    yaml += &format!(
+                        "    parent: {{ type: {}, title: A container, volume: 2 }}\n",
+                        parent
+                    );
+                }
+
+                let res = format(&Ieee::new(), &yaml);
+                assert!(res.contains("Doe"), "{} in {:?}: {}", child, parent, res);
+            }
+        }
+    }
+
+    #[test]
+    fn container_title_duplicate() {
+        let yaml = r#"
+        chapter:
+            type: Chapter
+            title: Handbook of Learning
+            author: Doe, Jane
+            parent:
+                type: Anthology
+                title: Handbook of learning
+                publisher: ACME
+                date: 2001
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let reference = Ieee::new().reference(&Record::from_entry(&entries[0]));
+        assert_eq!(reference.display.value, "J. Doe, Handbook Learn., ACME, 2001.");
+        assert_eq!(reference.display.formatting, vec![(8..23, Formatting::Italic)]);
+    }
+
+    #[test]
+    fn multiple_languages() {
+        let yaml = r#"
+        treaty:
+            type: Book
+            title: Treaty of friendship
+            publisher: Queen's Printer
+            location: Ottawa
+            languages: [en, fr]
+            date: 1982
+        "#;
+
+        let mut ieee = Ieee::new();
+        assert_eq!(
+            format(&ieee, yaml),
+            "Treaty of Friendship, Ottawa: Queen's Printer (in English and French), 1982."
+        );
+
+        let trilingual = yaml.replace("[en, fr]", "[en, fr, de]");
+        assert_eq!(
+            format(&ieee, &trilingual),
+            "Treaty of Friendship, Ottawa: Queen's Printer (in English, French, and \
+             German), 1982."
+        );
+
+        // Configured annotations and the conjunction come from the locale.
+        let german = ieee.with_locale(&"de".parse().unwrap());
+        assert_eq!(
+            format(&german, yaml),
+            "Treaty of Friendship, Ottawa: Queen's Printer (auf Englisch und auf \
+             Französisch), 1982."
+        );
+
+        ieee.locale
+            .language_annotations
+            .insert("fr".into(), "in French (Canada)".into());
+        assert_eq!(
+            format(&ieee, &trilingual),
+            "Treaty of Friendship, Ottawa: Queen's Printer (in English, in French \
+             (Canada), and in German), 1982."
+        );
+    }
+
+    #[test]
+    fn online_reference() {
+        let yaml = r#"
+        rayleigh:
+            type: Entry
+            title: Rayleigh scattering
+            author: Doe, Jane
+            url: { value: "https://example.org/rayleigh", date: 2021-03-04 }
+            parent:
+                type: Reference
+                title: Encyclopedia of Optics
+                publisher: Wiley
+                location: Hoboken
+                date: 2020
+        "#;
+
+        assert_eq!(
+            format(&Ieee::new(), yaml),
+            "J. Doe, “Rayleigh scattering,” in Encyclopedia Opt., Wiley, Hoboken, \
+             2020. Accessed: Mar. 4, 2021. [Online]. Available: \
+             https://example.org/rayleigh"
+        );
+    }
+}
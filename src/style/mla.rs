@@ -7,7 +7,7 @@ use super::{
     DisplayReference, DisplayString, Formatting, Record,
 };
 use crate::lang::{en, TitleCase};
-use crate::types::{Date, EntryType::*, FmtOptionExt, NumOrStr, Person, PersonRole};
+use crate::types::{Date, EntryType::*, FmtListExt, NumOrStr, Person, PersonRole};
 use crate::Entry;
 
 /// Bibliographies following MLA guidance.
@@ -164,9 +164,9 @@ fn format_date(d: &Date) -> String {
     let mut res = String::new();
     if let Some(month) = d.month {
         res.push_str(&if let Some(day) = d.day {
-            format!("{} {} ", day + 1, en::get_month_abbr(month, true).unwrap())
+            format!("{} {} ", day + 1, en::get_month_abbr(month, true, None).unwrap())
         } else {
-            format!("{} ", en::get_month_abbr(month, true).unwrap())
+            format!("{} ", en::get_month_abbr(month, true, None).unwrap())
         });
     }
 
@@ -506,6 +506,9 @@ impl Mla {
                             PersonRole::Director => "directed by",
                             PersonRole::Illustrator => "illustrated by",
                             PersonRole::Narrator => "narrated by",
+                            PersonRole::Subtitler => "subtitled by",
+                            PersonRole::Presenter => "presented by",
+                            PersonRole::Contributor => "with",
                             PersonRole::Unknown(_) => "",
                         };
 
@@ -588,10 +591,14 @@ impl Mla {
 
             // Publisher
             if !select!((Manuscript > (!*)) | Periodical).matches(entry) {
-                if let Some(publisher) =
-                    entry.publisher().value().or_else(|| entry.organization())
-                {
-                    container.publisher = abbreviate_publisher(publisher, true);
+                if let Some(publishers) = entry.publishers().filter(|p| !p.is_empty()) {
+                    container.publisher = publishers
+                        .iter()
+                        .map(|p| abbreviate_publisher(&p.value, true))
+                        .collect::<Vec<_>>()
+                        .join(" / ");
+                } else if let Some(organization) = entry.organization() {
+                    container.publisher = abbreviate_publisher(organization, true);
                 }
             }
 
@@ -610,16 +617,16 @@ impl Mla {
             let mut location: Vec<DisplayString> = vec![];
             let physical =
                 select!(Scene | Artwork | Case | Conference | Exhibition).matches(entry);
-            if physical || self.always_use_location || entry.publisher().is_none() {
-                if let Some(loc) = entry.location() {
-                    location.push(DisplayString::from_string(loc.value.clone()));
+            if physical || self.always_use_location || entry.publishers().is_none() {
+                if let Some(loc) = entry.locations().joined("; ") {
+                    location.push(DisplayString::from_string(loc));
                 }
             }
             if let Some(page_range) = entry.page_range() {
                 location.push(format_range("p.", "pp.", page_range).into());
             }
 
-            if entry.publisher().is_some() && entry.organization().is_some() {
+            if entry.publishers().is_some() && entry.organization().is_some() {
                 location.push(entry.organization().unwrap().into());
             }
 
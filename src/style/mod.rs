@@ -4,13 +4,18 @@ mod apa;
 mod chicago;
 mod ieee;
 mod mla;
+mod raw;
 
 pub use apa::Apa;
 pub use chicago::author_date::ChicagoAuthorDate;
 pub use chicago::notes::{ChicagoNoteStyle, ChicagoNotes};
 pub use chicago::{ChicagoAccessDateVisibility, ChicagoConfig};
-pub use ieee::Ieee;
+pub use ieee::{
+    Ieee, IeeeAccessDateVisibility, IeeeAddonBranch, IeeeCasing, IeeeDoiPeriod,
+    IeeeDoiPrefix, IeeeInitials, IeeeJournalResolver, IeeeLocale, IeeeYearFormat,
+};
 pub use mla::Mla;
+pub use raw::Raw;
 
 use std::fmt::{self, Debug, Display, Formatter, Write};
 use std::ops::{Add, AddAssign};
@@ -23,6 +28,21 @@ use unicode_segmentation::UnicodeSegmentation;
 use super::types::Person;
 use super::Entry;
 
+/// An event that occurred while formatting a reference, e.g. an optional
+/// field that was used or skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatEvent {
+    /// The DOI of the entry was printed.
+    UsedDoi,
+    /// The URL of the entry was printed.
+    UsedUrl,
+    /// The language of the entry was skipped because its code is unknown.
+    SkippedLanguage(String),
+    /// The entry and its parents have no date to print.
+    MissingDate,
+}
+
 /// A database record that contains some style-set supplementary info.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -47,18 +67,61 @@ impl<'a> Record<'a> {
 }
 
 /// A citation of a single entry.
+///
+/// Create citations with [`Citation::new`] and point them to a part of the
+/// entry with [`Citation::with_locator`].
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct Citation<'a> {
     /// The cited entry.
     pub entry: &'a Entry,
     /// Supplement for the entry such as page or chapter number.
     pub supplement: Option<&'a str>,
+    /// A figure, table, or equation within the entry that is cited.
+    pub locator: Option<Locator<'a>>,
 }
 
 impl<'a> Citation<'a> {
     /// Create a new citation.
     pub fn new(entry: &'a Entry, supplement: Option<&'a str>) -> Self {
-        Self { entry, supplement }
+        Self { entry, supplement, locator: None }
+    }
+
+    /// Point the citation to a figure, table, or equation within the entry.
+    pub fn with_locator(self, locator: Locator<'a>) -> Self {
+        Self { locator: Some(locator), ..self }
+    }
+
+    /// The supplement followed by the locator, if any of them is present.
+    fn full_supplement(&self) -> Option<String> {
+        let locator = self.locator.map(|l| l.to_string());
+        match (self.supplement, locator) {
+            (Some(s), Some(l)) => Some(format!("{}, {}", s, l)),
+            (Some(s), None) => Some(s.to_string()),
+            (None, l) => l,
+        }
+    }
+}
+
+/// A part of an entry such as a figure that a citation points to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Locator<'a> {
+    /// A figure, rendered as "Fig. 3".
+    Figure(&'a str),
+    /// A table, rendered as "Tbl. 2".
+    Table(&'a str),
+    /// An equation, rendered as "eq. (5)".
+    Equation(&'a str),
+}
+
+impl Display for Locator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Figure(n) => write!(f, "Fig. {}", n),
+            Self::Table(n) => write!(f, "Tbl. {}", n),
+            Self::Equation(n) => write!(f, "eq. ({})", n),
+        }
     }
 }
 
@@ -146,6 +209,20 @@ impl<'a> Database<'a> {
         }
     }
 
+    /// Reorders the records by a custom key of their entries, e.g. the name
+    /// of the journal they appeared in.
+    ///
+    /// The sort is stable, so records with equal keys keep their relative
+    /// order. The new order is used by bibliographies with
+    /// [`BibliographyOrdering::ByInsertionOrder`] and by numeric citations
+    /// that are assigned in insertion order afterwards.
+    pub fn sort_by_key<K: Ord>(&mut self, f: impl Fn(&Entry) -> K) {
+        let mut records =
+            std::mem::take(&mut self.records).into_iter().collect::<Vec<_>>();
+        records.sort_by_key(|(_, record)| f(record.entry));
+        self.records = records.into_iter().collect();
+    }
+
     fn records(&self) -> linked_hash_map::Values<&'a str, Record<'a>> {
         self.records.values()
     }
@@ -193,6 +270,71 @@ impl<'a> Database<'a> {
         style.bibliography(self, ordering.unwrap_or_else(|| style.ordering()))
     }
 
+    /// Creates a bibliography like [`Self::bibliography`], but formats the
+    /// entries whose `style` field names one of the `overrides` with that
+    /// style instead.
+    ///
+    /// The order and prefixes of the references are still determined by
+    /// `style`.
+    pub fn bibliography_with_overrides<S>(
+        &self,
+        style: &S,
+        overrides: &[(&str, &dyn BibliographyStyle<'a>)],
+        ordering: Option<BibliographyOrdering>,
+    ) -> Vec<DisplayReference<'a>>
+    where
+        S: BibliographyStyle<'a> + ?Sized,
+    {
+        let mut bib = self.bibliography(style, ordering);
+        for reference in &mut bib {
+            let entry = reference.entry;
+            let override_style = entry.style().and_then(|name| {
+                overrides.iter().find(|(n, _)| *n == name).map(|&(_, s)| s)
+            });
+
+            if let (Some(override_style), Some(record)) =
+                (override_style, self.records.get(entry.key()))
+            {
+                reference.display = override_style.reference(record).display;
+            }
+        }
+
+        bib
+    }
+
+    /// Format a bibliography like [`Self::bibliography`] and return the plain
+    /// text of each reference together with its [spans](DisplayString::spans).
+    ///
+    /// This is useful for applications that render the bibliography to
+    /// several backends at once.
+    pub fn render_structured<S>(
+        &self,
+        style: &S,
+        ordering: Option<BibliographyOrdering>,
+    ) -> Vec<(String, Vec<Span>)>
+    where
+        S: BibliographyStyle<'a> + ?Sized,
+    {
+        self.bibliography(style, ordering)
+            .into_iter()
+            .map(|reference| {
+                let spans = reference.display.spans();
+                (reference.display.value, spans)
+            })
+            .collect()
+    }
+
+    /// Returns the number a numeric citation style assigned to the entry with
+    /// the given key.
+    ///
+    /// The number is also available as the
+    /// [prefix](DisplayReference::prefix) of the entry's reference in the
+    /// bibliography. Returns `None` if the entry is not in the database or has
+    /// not been cited with a numeric style yet.
+    pub fn number_of(&self, key: &str) -> Option<usize> {
+        self.records.get(key)?.prefix.as_ref()?.parse().ok()
+    }
+
     /// Format a single entry for a bibliography with the given style.
     ///
     /// Returns the entry along with its styled reference if it is present in
@@ -327,9 +469,9 @@ impl<'a> CitationStyle<'a> for Keys {
             res += atomic.entry.key();
             res.commit_formats();
 
-            if let Some(supplement) = atomic.supplement {
+            if let Some(supplement) = atomic.full_supplement() {
                 res += " (";
-                res += supplement;
+                res += &supplement;
                 res.push(')');
             }
 
@@ -350,12 +492,17 @@ impl<'a> CitationStyle<'a> for Keys {
 
 /// Output IEEE-style numerical reference markers.
 ///
-/// An example would be 1 or 3-7; 9.
+/// An example would be 1 or 3-7; 9. With [`superscript`](Self::superscript)
+/// set, Vancouver-style unbracketed superscript markers like 1,3-5 are
+/// produced instead.
 #[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct Numerical {
     used_numbers: Vec<usize>,
     /// Order of the numeric references.
     pub ordering: NumericalOrdering,
+    /// Whether to render the numbers as comma-separated superscript without
+    /// brackets.
+    pub superscript: bool,
 }
 
 impl Numerical {
@@ -363,6 +510,21 @@ impl Numerical {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Renders the in-text marker for the reference with the given number,
+    /// e.g. "\[3\]", or a superscript "3" if
+    /// [`superscript`](Self::superscript) is set.
+    pub fn label(&self, n: usize) -> DisplayString {
+        let mut res = DisplayString::new();
+        if self.superscript {
+            res.start_format(Formatting::Superscript);
+            res += &n.to_string();
+            res.commit_formats();
+        } else {
+            res += &format!("[{}]", n);
+        }
+        res
+    }
 }
 
 /// Specify the order in which numbers are assigned.
@@ -427,7 +589,7 @@ impl<'a> CitationStyle<'a> for Numerical {
                         Some((counter + 1).to_string());
                     counter + 1
                 };
-                ids.push((number, atomic.supplement));
+                ids.push((number, atomic.full_supplement()));
             }
         } else {
             for atomic in parts {
@@ -447,15 +609,15 @@ impl<'a> CitationStyle<'a> for Numerical {
                         Some(n.to_string());
                     n
                 };
-                ids.push((number, atomic.supplement));
+                ids.push((number, atomic.full_supplement()));
             }
         }
 
         ids.sort_by(|(a, _), (b, _)| a.cmp(&b));
 
-        enum CiteElement<'a> {
+        enum CiteElement {
             Range(std::ops::Range<usize>),
-            Single((usize, Option<&'a str>)),
+            Single((usize, Option<String>)),
         }
 
         let mut res_elems = vec![];
@@ -489,26 +651,36 @@ impl<'a> CitationStyle<'a> for Numerical {
                     format!("{}-{}", r.start, r.end)
                 }
                 CiteElement::Range(r) => r.start.to_string(),
-                CiteElement::Single((n, s)) => {
-                    if let Some(sup) = s {
-                        format!("{}, {}", n, sup)
-                    } else {
-                        n.to_string()
-                    }
-                }
+                CiteElement::Single((n, s)) => match s {
+                    Some(sup) if self.superscript => format!("{}({})", n, sup),
+                    Some(sup) => format!("{}, {}", n, sup),
+                    None => n.to_string(),
+                },
             })
             .collect::<Vec<_>>()
-            .join("; ");
+            .join(if self.superscript { "," } else { "; " });
 
-        DisplayCitation::new(re.into(), false)
+        if self.superscript {
+            let mut res = DisplayString::new();
+            res.start_format(Formatting::Superscript);
+            res += &re;
+            res.commit_formats();
+            DisplayCitation::new(res, false)
+        } else {
+            DisplayCitation::new(re.into(), false)
+        }
     }
 
     fn brackets(&self) -> Brackets {
-        Brackets::Square
+        if self.superscript {
+            Brackets::None
+        } else {
+            Brackets::Square
+        }
     }
 
     fn wrapped(&self) -> bool {
-        true
+        !self.superscript
     }
 }
 
@@ -535,11 +707,49 @@ fn name_list(persons: &[Person]) -> Vec<String> {
     names
 }
 
-fn name_list_straight(persons: &[Person]) -> Vec<String> {
+/// Which given names of a name list are abbreviated to their initials.
+///
+/// Numeric styles such as IEEE abbreviate every given name while Chicago
+/// spells them out in full. The styles expose this as an option, e.g.
+/// [`Apa::initial_abbreviation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InitialAbbreviation {
+    /// Abbreviate all given names: "J. Doe and J. Roe".
+    Always,
+    /// Spell out all given names: "Jane Doe and John Roe".
+    Never,
+    /// Spell out the name of the first person with the family name first
+    /// and abbreviate the others: "Doe, Jane and J. Roe".
+    AllButFirst,
+}
+
+impl InitialAbbreviation {
+    /// Whether the given names of the person at `index` are abbreviated.
+    pub(crate) fn abbreviates(self, index: usize) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::AllButFirst => index > 0,
+        }
+    }
+}
+
+/// Formats persons with their given names first (e.g. "J. A. Smith"),
+/// abbreviating the given names as configured.
+fn name_list_straight(
+    persons: &[Person],
+    abbreviation: InitialAbbreviation,
+) -> Vec<String> {
     let mut names = vec![];
 
-    for author in persons.iter() {
-        names.push(author.given_first(true));
+    for (i, author) in persons.iter().enumerate() {
+        names.push(if abbreviation.abbreviates(i) {
+            author.given_first(true)
+        } else if abbreviation == InitialAbbreviation::AllButFirst {
+            author.name_first(false, false)
+        } else {
+            author.given_first(false)
+        });
     }
 
     names
@@ -547,6 +757,7 @@ fn name_list_straight(persons: &[Person]) -> Vec<String> {
 
 /// Formatting modifiers for strings.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Formatting {
     /// **Bold print**
     Bold,
@@ -554,6 +765,18 @@ pub enum Formatting {
     Italic,
     /// Should link to the given URL.
     Link(String),
+    /// Raised, smaller print (e. g. for superscript citation numbers).
+    Superscript,
+}
+
+/// A run of text within a [`DisplayString`] that has the same formatting
+/// throughout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// The text of the span.
+    pub text: String,
+    /// All formatting modifiers that apply to the text.
+    pub formatting: Vec<Formatting>,
 }
 
 /// Will move a format range's indices by `o`.
@@ -610,6 +833,16 @@ impl DisplayString {
         self.value.is_empty()
     }
 
+    /// Get the number of user-perceived characters in the plain text.
+    pub fn char_count(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Get the number of whitespace-separated words in the plain text.
+    pub fn word_count(&self) -> usize {
+        self.value.split_whitespace().count()
+    }
+
     /// Get the last character.
     pub fn last(&self) -> Option<char> {
         self.value.chars().last()
@@ -691,13 +924,40 @@ impl DisplayString {
         res
     }
 
+    /// Splits the string into runs of uniformly formatted text.
+    ///
+    /// The texts of the spans concatenate to the plain string value.
+    pub fn spans(&self) -> Vec<Span> {
+        let mut bounds = vec![0, self.len()];
+        for (range, _) in &self.formatting {
+            bounds.push(range.start.min(self.len()));
+            bounds.push(range.end.min(self.len()));
+        }
+
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        bounds
+            .windows(2)
+            .map(|w| Span {
+                text: self.value[w[0]..w[1]].to_string(),
+                formatting: self
+                    .formatting
+                    .iter()
+                    .filter(|(range, _)| range.start <= w[0] && w[1] <= range.end)
+                    .map(|(_, f)| f.clone())
+                    .collect(),
+            })
+            .collect()
+    }
+
     /// Applies the formatting as ANSI / VT100 control sequences.
     pub fn ansi_vt100(&self) -> String {
         let mut start_end = vec![];
 
         for item in &self.formatting {
             let opt = &item.1;
-            if matches!(opt, Formatting::Link(_)) {
+            if matches!(opt, Formatting::Link(_) | Formatting::Superscript) {
                 continue;
             }
             let min = item.0.start;
@@ -722,7 +982,7 @@ impl DisplayString {
                 match f {
                     Formatting::Bold => "1",
                     Formatting::Italic => "3",
-                    Formatting::Link(_) => unreachable!(),
+                    Formatting::Link(_) | Formatting::Superscript => unreachable!(),
                 }
             };
             res = format!("\x1b[{}m", code) + &res;
@@ -829,12 +1089,21 @@ impl From<&str> for DisplayString {
 }
 
 fn push_comma_quote_aware(s: &mut String, comma: char, space: bool) {
-    let cur_len = s.len();
-    if cur_len > 3 && s.is_char_boundary(cur_len - 3) && &s[cur_len - 3..] == "”" {
-        s.truncate(cur_len - 3);
+    push_comma_quote_aware_with(s, comma, space, "”")
+}
+
+/// Pushes a punctuation mark, tucking it in front of a trailing `close_quote`.
+fn push_comma_quote_aware_with(
+    s: &mut String,
+    comma: char,
+    space: bool,
+    close_quote: &str,
+) {
+    if !close_quote.is_empty() && s.ends_with(close_quote) {
+        s.truncate(s.len() - close_quote.len());
         if !s.ends_with(comma) {
             s.push(comma);
-            s.push('”');
+            s.push_str(close_quote);
         }
     } else if !s.is_empty() && !s.ends_with(comma) {
         s.push(comma);
@@ -1118,9 +1387,9 @@ impl<'a> CitationStyle<'a> for Alphanumerical {
             let record = db.records.get_mut(atomic.entry.key()).unwrap();
             record.prefix = Some(res.clone());
 
-            if let Some(supplement) = atomic.supplement {
+            if let Some(supplement) = atomic.full_supplement() {
                 res += ", ";
-                res += supplement;
+                res += &supplement;
             }
 
             items.push(res);
@@ -1207,9 +1476,9 @@ impl<'a> CitationStyle<'a> for AuthorTitle {
                 res += ")";
             }
 
-            if let Some(supplement) = atomic.supplement {
+            if let Some(supplement) = atomic.full_supplement() {
                 push_comma_quote_aware(&mut res.value, ',', true);
-                res += supplement;
+                res += &supplement;
             }
 
             items.push(res);
@@ -1226,3 +1495,330 @@ impl<'a> CitationStyle<'a> for AuthorTitle {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::from_yaml_str;
+
+    #[test]
+    fn superscript_numerical() {
+        let yaml = r#"
+        a: { type: Misc, title: A }
+        b: { type: Misc, title: B }
+        c: { type: Misc, title: C }
+        d: { type: Misc, title: D }
+        e: { type: Misc, title: E }
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut db = Database::from_entries(entries.iter());
+        let mut style = Numerical::new();
+        style.superscript = true;
+
+        // Assign the numbers in order before citing a subset.
+        for entry in &entries {
+            db.citation(&mut style, &[Citation::new(entry, None)]);
+        }
+
+        let parts: Vec<_> = [0, 2, 3, 4]
+            .iter()
+            .map(|&i| Citation::new(&entries[i], None))
+            .collect();
+        let cite = db.citation(&mut style, &parts);
+
+        assert_eq!(cite.display.value, "1,3-5");
+        assert_eq!(cite.display.formatting, vec![(0..5, Formatting::Superscript)]);
+        assert_eq!(style.brackets(), Brackets::None);
+        assert!(!style.wrapped());
+    }
+
+    #[test]
+    fn locators() {
+        let entries = from_yaml_str("a: { type: Misc, title: A }").unwrap();
+        let mut db = Database::from_entries(entries.iter());
+        let mut style = Numerical::new();
+
+        let mut cite = |locator| {
+            let citation = Citation::new(&entries[0], None).with_locator(locator);
+            db.citation(&mut style, &[citation]).display.value
+        };
+
+        assert_eq!(cite(Locator::Figure("3")), "1, Fig. 3");
+        assert_eq!(cite(Locator::Table("2")), "1, Tbl. 2");
+        assert_eq!(cite(Locator::Equation("5")), "1, eq. (5)");
+
+        let citation =
+            Citation::new(&entries[0], Some("p. 5")).with_locator(Locator::Figure("3"));
+        assert_eq!(
+            db.citation(&mut Keys::new(), &[citation]).display.value,
+            "a (p. 5, Fig. 3)"
+        );
+    }
+
+    #[test]
+    fn reference_numbers() {
+        let yaml = r#"
+        a: { type: Misc, title: A }
+        b: { type: Misc, title: B }
+        c: { type: Misc, title: C }
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut db = Database::from_entries(entries.iter());
+        let mut style = Numerical::new();
+
+        let cite_c = db.citation(&mut style, &[Citation::new(&entries[2], None)]);
+        let cite_a = db.citation(&mut style, &[Citation::new(&entries[0], None)]);
+        assert_eq!(cite_c.display.value, "1");
+        assert_eq!(cite_a.display.value, "2");
+
+        assert_eq!(db.number_of("c"), Some(1));
+        assert_eq!(db.number_of("a"), Some(2));
+        assert_eq!(db.number_of("b"), None);
+        assert_eq!(db.number_of("z"), None);
+
+        let bib = db.bibliography(&Ieee::new(), None);
+        for reference in &bib {
+            let prefix = reference.prefix.as_ref().map(|p| p.value.as_str());
+            let number = db.number_of(reference.entry.key()).map(|n| n.to_string());
+            assert_eq!(prefix, number.as_deref());
+        }
+    }
+
+    #[test]
+    fn numerical_label() {
+        let mut style = Numerical::new();
+        assert_eq!(style.label(3).value, "[3]");
+        assert!(style.label(3).formatting.is_empty());
+
+        style.superscript = true;
+        let label = style.label(12);
+        assert_eq!(label.value, "12");
+        assert_eq!(label.formatting, vec![(0..2, Formatting::Superscript)]);
+    }
+
+    #[test]
+    fn page_counts() {
+        let pages =
+            |s: &str| format_range("p.", "pp.", &crate::types::parse_range(s).unwrap());
+
+        assert_eq!(pages("5"), "p. 5");
+        assert_eq!(pages("5-5"), "p. 5");
+        assert_eq!(pages("5-9"), "pp. 5–9");
+    }
+
+    #[test]
+    fn word_count() {
+        let entries = from_yaml_str(
+            r#"
+            kinetics:
+                type: Article
+                title: Kinetics of excitations
+                author: Doan, T. D.
+                date: 2020
+                parent:
+                    type: Periodical
+                    title: Physical Review B
+            "#,
+        )
+        .unwrap();
+
+        let db = Database::from_entries(entries.iter());
+        let display = &db.bibliography(&Ieee::new(), None)[0].display;
+
+        assert_eq!(
+            display.value,
+            "T. D. Doan, “Kinetics of excitations,” Physical Rev. B, 2020."
+        );
+        assert_eq!(display.word_count(), 10);
+        assert_eq!(display.char_count(), 61);
+        assert_eq!(DisplayString::new().word_count(), 0);
+    }
+
+    #[test]
+    fn style_overrides() {
+        let yaml = r#"
+        paper:
+            type: Article
+            title: Kinetics of excitations
+            author: Doan, T. D.
+            date: 2020
+        statute:
+            type: Book
+            title: The civil code
+            author: Doe, Jane
+            date: 1900
+            style: humanities
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let ieee = Ieee::new();
+        let mla = Mla::new();
+
+        let plain = db.bibliography(&ieee, None);
+        let bib = db.bibliography_with_overrides(&ieee, &[("humanities", &mla)], None);
+        assert_eq!(bib.len(), 2);
+        assert_eq!(bib[0].display.value, plain[0].display.value);
+        assert_eq!(bib[0].prefix, plain[0].prefix);
+        assert_eq!(bib[1].prefix, plain[1].prefix);
+        assert_ne!(bib[1].display.value, plain[1].display.value);
+        assert_eq!(
+            bib[1].display.value,
+            db.reference(&mla, "statute").unwrap().display.value
+        );
+    }
+
+    #[test]
+    fn co_publishers() {
+        let yaml = r#"
+        handbook:
+            type: Book
+            title: Handbook of robotics
+            author: Siciliano, Bruno
+            date: 2016
+            publisher: [Springer Inc., MIT Press]
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let ieee = db.reference(&Ieee::new(), "handbook").unwrap();
+        let mla = db.reference(&Mla::new(), "handbook").unwrap();
+        assert_eq!(
+            ieee.display.value,
+            "B. Siciliano, Handbook of Robotics, Springer Inc.; MIT Press, 2016."
+        );
+        assert_eq!(
+            mla.display.value,
+            "Siciliano, Bruno. Handbook of Robotics. Springer / MIT P, 2016."
+        );
+    }
+
+    #[test]
+    fn multiple_locations() {
+        let yaml = r#"
+        trial:
+            type: Book
+            title: The trial
+            author: Kafka, Franz
+            date: 1998
+            publisher: Schocken Books
+            location: [New York, London]
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let ieee = db.reference(&Ieee::new(), "trial").unwrap();
+        let chicago = db.reference(&ChicagoAuthorDate::new(), "trial").unwrap();
+        assert_eq!(
+            ieee.display.value,
+            "F. Kafka, The Trial, New York; London: Schocken Books, 1998."
+        );
+        assert_eq!(
+            chicago.display.value,
+            "Kafka, Franz. 1998. The Trial. New York: Schocken Books."
+        );
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let yaml = r#"
+        a:
+            type: Article
+            title: A
+            date: 2020
+            parent: { type: Periodical, title: Physical Review B }
+        b:
+            type: Article
+            title: B
+            date: 2018
+            parent: { type: Periodical, title: Nature }
+        c:
+            type: Article
+            title: C
+            date: 2015
+            parent: { type: Periodical, title: Physical Review B }
+        d:
+            type: Article
+            title: D
+            date: 2019
+            parent: { type: Periodical, title: Nature }
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut db = Database::from_entries(entries.iter());
+        db.sort_by_key(|entry| {
+            let journal = entry
+                .parents()
+                .and_then(|parents| parents.first())
+                .and_then(|parent| parent.title())
+                .map(|title| title.canonical.value.clone());
+            (journal, entry.date().map(|date| date.year))
+        });
+
+        let bib = db.bibliography(&Raw::new(), None);
+        let keys: Vec<_> = bib.iter().map(|r| r.entry.key()).collect();
+        assert_eq!(keys, ["b", "d", "c", "a"]);
+    }
+
+    #[test]
+    fn initial_abbreviation() {
+        let persons = vec![
+            Person::from_strings(&["Doe", "Jane Ann"]).unwrap(),
+            Person::from_strings(&["Roe", "John"]).unwrap(),
+            Person::from_strings(&["Poe", "Edgar Allan"]).unwrap(),
+        ];
+
+        assert_eq!(
+            name_list_straight(&persons, InitialAbbreviation::Always),
+            ["J. A. Doe", "J. Roe", "E. A. Poe"]
+        );
+        assert_eq!(
+            name_list_straight(&persons, InitialAbbreviation::Never),
+            ["Jane Ann Doe", "John Roe", "Edgar Allan Poe"]
+        );
+        assert_eq!(
+            name_list_straight(&persons, InitialAbbreviation::AllButFirst),
+            ["Doe, Jane Ann", "J. Roe", "E. A. Poe"]
+        );
+    }
+
+    #[test]
+    fn render_structured() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics and luminescence of the excitations
+            author: ["Doan, T. D.", "Haug, H."]
+            date: 2020
+            parent:
+                type: Periodical
+                title: Physical Review B
+                volume: 102
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            date: 1998
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let rendered = db.render_structured(&Ieee::new(), None);
+        assert_eq!(rendered.len(), 2);
+
+        for (text, spans) in &rendered {
+            let joined: String = spans.iter().map(|span| span.text.as_str()).collect();
+            assert_eq!(text, &joined);
+        }
+
+        let (_, spans) = &rendered[0];
+        let italic: Vec<_> = spans
+            .iter()
+            .filter(|span| span.formatting == [Formatting::Italic])
+            .map(|span| span.text.as_str())
+            .collect();
+        assert_eq!(italic, ["Physical Rev. B"]);
+    }
+}
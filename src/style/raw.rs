@@ -0,0 +1,139 @@
+//! A style that dumps the raw data of entries for debugging.
+
+use super::{
+    sorted_bibliography, BibliographyOrdering, BibliographyStyle, Database,
+    DisplayReference, DisplayString, Record,
+};
+use crate::types::{Date, Person};
+
+/// Bibliographies that reproduce the raw fields of each entry.
+///
+/// Every reference is a single line of plain text with the fields separated
+/// by vertical bars: entry type, key, authors, title, date, and the titles
+/// of the entry's parents. Missing fields are left empty so that columns stay
+/// stable. This is useful for debugging and diffing datasets rather than for
+/// presentation.
+///
+/// # Example
+/// - book | trial | Kafka, Franz | The Trial | 1998 |
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Raw {}
+
+impl Raw {
+    /// Creates a new raw bibliography generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn get_single_record<'a>(
+        &self,
+        record: &Record<'a>,
+    ) -> (DisplayReference<'a>, Vec<Person>) {
+        let entry = record.entry;
+        let authors = entry.authors().unwrap_or_default();
+
+        let fields = [
+            entry.kind().to_string(),
+            entry.key().to_string(),
+            authors
+                .iter()
+                .map(|p| p.name_first(false, false))
+                .collect::<Vec<_>>()
+                .join("; "),
+            entry.title().map(|t| t.canonical.value.clone()).unwrap_or_default(),
+            entry.date().map(format_date).unwrap_or_default(),
+            entry
+                .parents()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|p| p.title().map(|t| t.canonical.value.clone()))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ];
+
+        (
+            DisplayReference::new(
+                entry,
+                record.prefix.clone().map(Into::into),
+                DisplayString::from_string(fields.join(" | ").trim_end()),
+            ),
+            authors.to_vec(),
+        )
+    }
+}
+
+fn format_date(date: &Date) -> String {
+    let mut res = format!("{:04}", date.year);
+    if let Some(month) = date.month {
+        res += &format!("-{:02}", month + 1);
+        if let Some(day) = date.day {
+            res += &format!("-{:02}", day + 1);
+        }
+    }
+    res
+}
+
+impl<'a> BibliographyStyle<'a> for Raw {
+    fn bibliography(
+        &self,
+        db: &Database<'a>,
+        ordering: BibliographyOrdering,
+    ) -> Vec<DisplayReference<'a>> {
+        let mut items = vec![];
+
+        for record in db.records() {
+            items.push(self.get_single_record(record));
+        }
+
+        sorted_bibliography(items, ordering)
+    }
+
+    fn reference(&self, record: &Record<'a>) -> DisplayReference<'a> {
+        self.get_single_record(record).0
+    }
+
+    fn ordering(&self) -> BibliographyOrdering {
+        BibliographyOrdering::ByInsertionOrder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Raw;
+    use crate::io::from_yaml_str;
+    use crate::style::Database;
+
+    #[test]
+    fn raw_line() {
+        let yaml = r#"
+        kinetics:
+            type: Article
+            title: Kinetics and luminescence of the excitations
+            author: ["Doan, T. D.", "Haug, H."]
+            date: 2020-10-14
+            parent:
+                type: Periodical
+                title: Physical Review B
+        trial:
+            type: Book
+            title: The Trial
+            author: Kafka, Franz
+            date: 1998
+        "#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let db = Database::from_entries(entries.iter());
+        let bib = db.bibliography(&Raw::new(), None);
+
+        assert_eq!(
+            bib[0].display.value,
+            "article | kinetics | Doan, T. D.; Haug, H. | Kinetics and luminescence \
+             of the excitations | 2020-10-14 | Physical Review B"
+        );
+        assert_eq!(
+            bib[1].display.value,
+            "book | trial | Kafka, Franz | The Trial | 1998 |"
+        );
+    }
+}
@@ -222,6 +222,13 @@ pub enum PersonRole {
     Illustrator,
     /// Provided narration or voice-over for the cited item.
     Narrator,
+    /// Translated the dialogue of the cited item into subtitles.
+    Subtitler,
+    /// Hosted or presented the cited item.
+    Presenter,
+    /// Assisted the authors in a secondary capacity and is credited "with"
+    /// them (e.g. "J. Smith, with K. Lee").
+    Contributor,
 
     /// Various other roles described by the contained string.
     #[strum(disabled)]
@@ -229,7 +236,11 @@ pub enum PersonRole {
 }
 
 /// Holds the name of a person.
+///
+/// Create persons with [`Person::from_strings`] and set further fields
+/// afterwards, as more fields may be added in the future.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct Person {
     /// The family name.
     pub name: String,
@@ -241,6 +252,40 @@ pub struct Person {
     pub suffix: Option<String>,
     /// Another name (often user name) the person might be known under.
     pub alias: Option<String>,
+    /// The institution the person is affiliated with.
+    pub affiliation: Option<String>,
+    /// The acronym of an organization, e.g. "NIH" for the National
+    /// Institutes of Health.
+    pub acronym: Option<String>,
+}
+
+impl Person {
+    /// Normalizes the whitespace in all parts of the name.
+    pub(crate) fn normalize(&mut self) {
+        self.name = normalize_whitespace(&self.name);
+        let parts = self
+            .given_name
+            .iter_mut()
+            .chain(self.prefix.iter_mut())
+            .chain(self.suffix.iter_mut())
+            .chain(self.alias.iter_mut())
+            .chain(self.affiliation.iter_mut())
+            .chain(self.acronym.iter_mut());
+        for part in parts {
+            *part = normalize_whitespace(part);
+        }
+    }
+}
+
+/// Collapses runs of whitespace into single spaces and trims the string.
+/// Non-breaking spaces are not considered to be whitespace.
+pub(crate) fn normalize_whitespace(s: &str) -> String {
+    let is_breaking =
+        |c: char| c.is_whitespace() && !matches!(c, '\u{a0}' | '\u{2007}' | '\u{202f}');
+    s.split(is_breaking)
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 /// Error that may occur when parsing a slice of strings as a name.
@@ -330,7 +375,15 @@ impl Person {
             name = name.trim_start().to_string();
         }
 
-        Ok(Person { name, given_name, prefix, suffix, alias: None })
+        Ok(Person {
+            name,
+            given_name,
+            prefix,
+            suffix,
+            alias: None,
+            affiliation: None,
+            acronym: None,
+        })
     }
 
     /// Formats the given name into initials.
@@ -621,6 +674,14 @@ pub struct FmtString {
 }
 
 impl FmtString {
+    /// Normalizes the whitespace in the value and its case overrides.
+    pub(crate) fn normalize(&mut self) {
+        self.value = normalize_whitespace(&self.value);
+        for case in self.title_case.iter_mut().chain(self.sentence_case.iter_mut()) {
+            *case = normalize_whitespace(case);
+        }
+    }
+
     /// Create a new formattable string.
     pub fn new(value: impl Into<String>) -> Self {
         Self {
@@ -665,41 +726,6 @@ impl FmtString {
     }
 }
 
-#[cfg(feature = "biblatex")]
-impl FmtString {
-    pub(crate) fn extend(&mut self, f2: Self) {
-        self.value += &f2.value;
-        self.verbatim = self.verbatim || f2.verbatim;
-        if let Some((t1, t2)) = self
-            .title_case
-            .clone()
-            .and_then(|t1| Some((t1, f2.clone().title_case?)))
-        {
-            self.title_case = Some(t1 + &t2);
-        } else {
-            self.title_case = None;
-        }
-        if let Some((t1, t2)) = self
-            .sentence_case
-            .clone()
-            .and_then(|t1| Some((t1, f2.sentence_case?)))
-        {
-            self.sentence_case = Some(t1 + &t2);
-        } else {
-            self.sentence_case = None;
-        }
-    }
-
-    pub(crate) fn new_empty(title: bool, sentence: bool, verbatim: bool) -> Self {
-        Self {
-            value: String::new(),
-            title_case: if title { Some(String::new()) } else { None },
-            sentence_case: if sentence { Some(String::new()) } else { None },
-            verbatim,
-        }
-    }
-}
-
 impl AsRef<str> for FmtString {
     fn as_ref(&self) -> &str {
         &self.value
@@ -767,6 +793,23 @@ impl<'a> FmtOptionExt<'a> for Option<&'a Title> {
     }
 }
 
+pub(crate) trait FmtListExt {
+    /// Joins the values of a list like the publishers of an entry with a
+    /// style's separator. Empty lists yield `None`.
+    fn joined(self, separator: &str) -> Option<String>;
+}
+
+impl FmtListExt for Option<&[FmtString]> {
+    fn joined(self, separator: &str) -> Option<String> {
+        self.filter(|list| !list.is_empty()).map(|list| {
+            list.iter()
+                .map(|fmt| fmt.value.as_str())
+                .collect::<Vec<_>>()
+                .join(separator)
+        })
+    }
+}
+
 /// An URL, possibly with a last visited date.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct QualifiedUrl {
@@ -790,6 +833,40 @@ pub(crate) fn parse_range(source: &str) -> Option<Range<i64>> {
     })
 }
 
+/// Splits a page string like "123-145" into its first and last page.
+///
+/// Any dash variant (including the en dash and repeated hyphens) separates
+/// the pages. A single page is both the first and the last page while
+/// open-ended inputs like "123+" or "123-" have no last page. Page
+/// identifiers need not be numeric, so "e12-e19" is split as well.
+pub fn parse_page_range(source: &str) -> (Option<String>, Option<String>) {
+    fn is_dash(c: char) -> bool {
+        matches!(c, '-' | '‐' | '‑' | '‒' | '–' | '—' | '−')
+    }
+
+    fn page(s: &str) -> Option<String> {
+        let s = s.trim();
+        if s.is_empty() {
+            None
+        } else {
+            Some(s.to_string())
+        }
+    }
+
+    let source = source.trim();
+    if let Some(first) = source.strip_suffix('+') {
+        return (page(first), None);
+    }
+
+    match source.find(is_dash) {
+        Some(index) => {
+            let last = source[index..].trim_start_matches(is_dash);
+            (page(&source[..index]), page(last))
+        }
+        None => (page(source), page(source)),
+    }
+}
+
 /// A duration.
 #[derive(Clone, Copy, Default, Debug, PartialEq)]
 pub struct Duration {
@@ -998,8 +1075,10 @@ macro_rules! impl_try_from_value {
 
 impl_try_from_value!(Title, Title);
 impl_try_from_value!(FmtString, FmtString);
+impl_try_from_value!(FmtStrings, Vec<FmtString>, [FmtString]);
 impl_try_from_value!(Text, String, str);
 impl_try_from_value!(Integer, i64);
+impl_try_from_value!(Bool, bool);
 impl_try_from_value!(Date, Date);
 impl_try_from_value!(Persons, Vec<Person>, [Person]);
 impl_try_from_value!(
@@ -1014,10 +1093,16 @@ impl_try_from_value!(TimeRange, std::ops::Range<Duration>);
 impl_try_from_value!(Url, QualifiedUrl);
 impl_try_from_value!(Language, unic_langid::LanguageIdentifier);
 impl_try_from_value!(Entries, Vec<Entry>, [Entry]);
+impl_try_from_value!(Keys, Vec<String>, [String]);
+impl_try_from_value!(
+    Languages,
+    Vec<unic_langid::LanguageIdentifier>,
+    [unic_langid::LanguageIdentifier],
+);
 
 #[cfg(test)]
 mod tests {
-    use super::Person;
+    use super::{parse_page_range, Person};
 
     #[test]
     fn person_initials() {
@@ -1026,4 +1111,21 @@ mod tests {
         let p = Person::from_strings(&["Günther", "Hans-Joseph"]).unwrap();
         assert_eq!("H-J", p.initials(None).unwrap());
     }
+
+    #[test]
+    fn page_ranges() {
+        let pages =
+            |first: &str, last: &str| (Some(first.to_string()), Some(last.to_string()));
+
+        assert_eq!(parse_page_range("123-145"), pages("123", "145"));
+        assert_eq!(parse_page_range("123 – 145"), pages("123", "145"));
+        assert_eq!(parse_page_range("123--145"), pages("123", "145"));
+        assert_eq!(parse_page_range("e12—e19"), pages("e12", "e19"));
+
+        assert_eq!(parse_page_range("42"), pages("42", "42"));
+
+        assert_eq!(parse_page_range("123+"), (Some("123".to_string()), None));
+        assert_eq!(parse_page_range("123-"), (Some("123".to_string()), None));
+        assert_eq!(parse_page_range(" "), (None, None));
+    }
 }